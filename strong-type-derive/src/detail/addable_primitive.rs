@@ -0,0 +1,16 @@
+use super::codegen_framework::generate_addable_primitive_operators;
+use crate::detail::{AutoOperatorMode, OperatorAttributes, generation_settings};
+use proc_macro2::TokenStream;
+
+/// Generates `Type ± primitive` operators, following the ownership variants and delegation
+/// strategy of the selected `auto_operators` mode.
+pub(crate) fn implement_addable_primitive(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    auto_operator_mode: AutoOperatorMode,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let (mode, strategy) = generation_settings(auto_operator_mode);
+    generate_addable_primitive_operators(name, value_type, mode, strategy, crate_path, attributes)
+}
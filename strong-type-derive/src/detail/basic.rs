@@ -1,68 +1,191 @@
+use crate::detail::{SkippableTrait, TypeMetadata, ValueTypeGroup, unless_skipped};
 use proc_macro2::TokenStream;
 use quote::quote;
 
-pub(crate) fn implement_basic(
-    name: &syn::Ident,
-    value_type: &syn::Ident,
-    primitive_type: &syn::Ident,
-) -> TokenStream {
-    quote! {
-        impl #name {
-            pub fn new(value: impl Into<#value_type>) -> Self {
-                Self(value.into())
-            }
+/// How `new` turns its argument into the wrapped value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Constructor {
+    /// Wraps the value as is
+    Plain,
+    /// Applies the generated `normalize` function first
+    Normalized,
+    /// Goes through the generated `try_new` and panics on invalid values
+    Validated,
+}
 
-            pub fn into_inner(self) -> #value_type {
-                self.0
-            }
+/// Visibility and mutability of the generated constructors and accessors
+pub(crate) struct Access {
+    /// Visibility of `new`, `try_new` and `const_new`
+    pub constructor: syn::Visibility,
+    /// Visibility of `into_inner`, `as_ref` and `as_mut`
+    pub accessor: syn::Visibility,
+    /// Whether `as_mut`, `AsMut` and `From<&mut Self>` are generated
+    pub mutable: bool,
+}
 
-            pub fn as_ref(&self) -> &#value_type {
-                &self.0
+impl Access {
+    /// Whether the constructors are public, so `FromStr` and `Default` construct nothing new
+    pub fn has_public_constructor(&self) -> bool {
+        matches!(self.constructor, syn::Visibility::Public(_))
+    }
+
+    /// Whether the accessors are public, so the matching trait impls expose nothing new
+    pub fn has_public_accessors(&self) -> bool {
+        matches!(self.accessor, syn::Visibility::Public(_))
+    }
+}
+
+pub(crate) fn implement_basic(metadata: &TypeMetadata, constructor: Constructor) -> TokenStream {
+    let name = &metadata.name;
+    let value_type = &metadata.value_type;
+    let primitive_type = &metadata.primitive_type;
+    let access = &metadata.access;
+    let crate_path = &metadata.crate_path;
+    let skipped = &metadata.skipped_traits;
+    let inline = metadata.inline_mode;
+    let constructor_vis = &access.constructor;
+    let accessor_vis = &access.accessor;
+
+    let new = match constructor {
+        Constructor::Plain => quote! {
+            #inline
+            #constructor_vis fn new(value: impl ::core::convert::Into<#value_type>) -> Self {
+                Self(::core::convert::Into::into(value))
             }
+        },
+        Constructor::Normalized => quote! {
+            #inline
+            #constructor_vis fn new(value: impl ::core::convert::Into<#value_type>) -> Self {
+                let value: #value_type = ::core::convert::Into::into(value);
+                Self(::core::convert::Into::into(Self::normalize(&value)))
+            }
+        },
+        Constructor::Validated => quote! {
+            /// Creates a value, panicking if it violates the constraints of this type.
+            /// See `try_new` for the fallible version.
+            #[track_caller]
+            #inline
+            #constructor_vis fn new(value: impl ::core::convert::Into<#value_type>) -> Self {
+                match Self::try_new(value) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(error) => ::core::panic!("{}", error),
+                }
+            }
+        },
+    };
 
-            pub fn as_mut(&mut self) -> &mut #value_type {
+    let as_mut = if access.mutable {
+        quote! {
+            #inline
+            #accessor_vis fn as_mut(&mut self) -> &mut #value_type {
                 &mut self.0
             }
         }
+    } else {
+        quote! {}
+    };
 
-        impl ::strong_type::StrongType for #name {
-            type UnderlyingType = #value_type;
-            type PrimitiveType = #primitive_type;
+    // The trait impls would make restricted accessors public again
+    let mut accessor_traits = quote! {};
+    if access.has_public_accessors() {
+        accessor_traits.extend(unless_skipped(
+            skipped,
+            SkippableTrait::AsRef,
+            quote! {
+                impl ::core::convert::AsRef<#value_type> for #name {
+                    #inline
+                    fn as_ref(&self) -> &#value_type {
+                        #name::as_ref(self)
+                    }
+                }
+            },
+        ));
+        if access.mutable {
+            accessor_traits.extend(unless_skipped(
+                skipped,
+                SkippableTrait::AsMut,
+                quote! {
+                    impl ::core::convert::AsMut<#value_type> for #name {
+                        #inline
+                        fn as_mut(&mut self) -> &mut #value_type {
+                            #name::as_mut(self)
+                        }
+                    }
+                },
+            ));
         }
+    }
 
-        impl std::fmt::Debug for #name {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                f.debug_struct(stringify!(#name))
-                 .field("value", &self.0)
-                 .finish()
-            }
+    // Opaque types only implement the traits required by `StrongType` when the wrapped type does
+    let bound = |traits: TokenStream| {
+        if matches!(metadata.type_group, ValueTypeGroup::Opaque) {
+            quote! { where for<'a> #value_type: #traits }
+        } else {
+            quote! {}
         }
+    };
+    let strong_type_bound = bound(quote! {
+        ::core::fmt::Debug
+            + ::core::cmp::PartialEq
+            + ::core::cmp::PartialOrd
+            + ::core::clone::Clone
+    });
+    let debug_bound = bound(quote!(::core::fmt::Debug));
+    let partial_eq_bound = bound(quote!(::core::cmp::PartialEq));
 
-        impl std::default::Default for #name
-        where
-            #value_type: std::default::Default,
-        {
-            fn default() -> Self {
-                Self::new(#value_type::default())
+    let debug = unless_skipped(
+        skipped,
+        SkippableTrait::Debug,
+        quote! {
+            impl ::core::fmt::Debug for #name #debug_bound {
+                #inline
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    f.debug_struct(::core::stringify!(#name))
+                     .field("value", &self.0)
+                     .finish()
+                }
             }
-        }
+        },
+    );
+    let partial_eq = unless_skipped(
+        skipped,
+        SkippableTrait::PartialEq,
+        quote! {
+            impl ::core::cmp::PartialEq for #name #partial_eq_bound {
+                #inline
+                fn eq(&self, rhs: &Self) -> bool {
+                    self.value() == rhs.value()
+                }
+            }
+        },
+    );
 
-        impl std::cmp::PartialEq for #name {
-            fn eq(&self, rhs: &Self) -> bool {
-                self.value() == rhs.value()
+    quote! {
+        impl #name {
+            #new
+
+            #inline
+            #accessor_vis fn into_inner(self) -> #value_type {
+                self.0
             }
-        }
 
-        impl std::convert::AsRef<#value_type> for #name {
-            fn as_ref(&self) -> &#value_type {
-                #name::as_ref(self)
+            #inline
+            #accessor_vis fn as_ref(&self) -> &#value_type {
+                &self.0
             }
+
+            #as_mut
         }
 
-        impl std::convert::AsMut<#value_type> for #name {
-            fn as_mut(&mut self) -> &mut #value_type {
-                #name::as_mut(self)
-            }
+        impl #crate_path::StrongType for #name #strong_type_bound {
+            type UnderlyingType = #value_type;
+            type PrimitiveType = #primitive_type;
         }
+
+        #debug
+
+        #partial_eq
+
+        #accessor_traits
     }
 }
@@ -1,41 +1,63 @@
+use crate::detail::{InlineMode, SkippableTrait, unless_skipped};
 use proc_macro2::TokenStream;
 use quote::quote;
+use std::collections::BTreeSet;
+
+pub(crate) fn implement_basic_primitive(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    constructor_vis: &syn::Visibility,
+    skipped: &BTreeSet<SkippableTrait>,
+    inline: InlineMode,
+) -> TokenStream {
+    let partial_ord = unless_skipped(
+        skipped,
+        SkippableTrait::PartialOrd,
+        quote! {
+            #[allow(clippy::incorrect_partial_ord_impl_on_ord_type)]
+            impl ::core::cmp::PartialOrd for #name {
+                #inline
+                fn partial_cmp(&self, rhs: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                     ::core::cmp::PartialOrd::partial_cmp(&self.value(), &rhs.value())
+                }
+            }
+        },
+    );
 
-pub(crate) fn implement_basic_primitive(name: &syn::Ident, value_type: &syn::Ident) -> TokenStream {
     quote! {
         impl #name {
+            #inline
             pub fn value(&self) -> #value_type {
                 self.0
             }
 
-            pub const fn const_new(value: #value_type) -> Self {
+            #inline
+            #constructor_vis const fn const_new(value: #value_type) -> Self {
                 Self(value)
             }
         }
 
-        impl Copy for #name {}
+        impl ::core::marker::Copy for #name {}
 
-        impl Clone for #name {
+        impl ::core::clone::Clone for #name {
+            #inline
             fn clone(&self) -> Self {
                 *self
             }
         }
 
-        #[allow(clippy::incorrect_partial_ord_impl_on_ord_type)]
-        impl std::cmp::PartialOrd for #name {
-            fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
-                 self.value().partial_cmp(&rhs.value())
-            }
-        }
+        #partial_ord
     }
 }
 
 pub(crate) fn implement_primitive_accessor(
     name: &syn::Ident,
-    primitive_type: &syn::Ident,
+    primitive_type: &syn::Type,
+    inline: InlineMode,
 ) -> TokenStream {
     quote! {
         impl #name {
+            #inline
             pub fn primitive(&self) -> #primitive_type {
                 self.value()
             }
@@ -45,10 +67,12 @@ pub(crate) fn implement_primitive_accessor(
 
 pub(crate) fn implement_primitive_accessor_derived(
     name: &syn::Ident,
-    primitive_type: &syn::Ident,
+    primitive_type: &syn::Type,
+    inline: InlineMode,
 ) -> TokenStream {
     quote! {
         impl #name {
+            #inline
             pub fn primitive(&self) -> #primitive_type {
                 self.0.primitive()
             }
@@ -1,29 +1,50 @@
+use crate::detail::{InlineMode, SkippableTrait, unless_skipped};
 use proc_macro2::TokenStream;
 use quote::quote;
+use std::collections::BTreeSet;
+
+pub(crate) fn implement_basic_string(
+    name: &syn::Ident,
+    skipped: &BTreeSet<SkippableTrait>,
+    inline: InlineMode,
+) -> TokenStream {
+    let partial_ord = unless_skipped(
+        skipped,
+        SkippableTrait::PartialOrd,
+        quote! {
+            impl ::core::cmp::PartialOrd for #name {
+                #inline
+                fn partial_cmp(&self, rhs: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                     ::core::option::Option::Some(::core::cmp::Ord::cmp(self, rhs))
+                }
+            }
+        },
+    );
 
-pub(crate) fn implement_basic_string(name: &syn::Ident) -> TokenStream {
     quote! {
-        impl Clone for #name {
+        impl ::core::clone::Clone for #name {
+            #inline
             fn clone(&self) -> Self {
-                Self(self.0.clone())
+                Self(::core::clone::Clone::clone(&self.0))
             }
         }
 
-        impl std::cmp::PartialOrd for #name {
-            fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
-                 Some(self.cmp(rhs))
-            }
-        }
+        #partial_ord
     }
 }
 
-pub(crate) fn implement_primitive_str_accessor(name: &syn::Ident) -> TokenStream {
+pub(crate) fn implement_primitive_str_accessor(
+    name: &syn::Ident,
+    inline: InlineMode,
+) -> TokenStream {
     quote! {
         impl #name {
+            #inline
             pub fn value(&self) -> &str {
-                self.0.as_str()
+                &self.0
             }
 
+            #inline
             pub fn primitive(&self) -> &str {
                 self.value()
             }
@@ -31,16 +52,35 @@ pub(crate) fn implement_primitive_str_accessor(name: &syn::Ident) -> TokenStream
     }
 }
 
+pub(crate) fn implement_static_str_constructor(
+    name: &syn::Ident,
+    constructor_vis: &syn::Visibility,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    quote! {
+        impl #name {
+            #inline
+            #constructor_vis const fn const_new(value: &'static str) -> Self {
+                Self(#crate_path::__private::Cow::Borrowed(value))
+            }
+        }
+    }
+}
+
 pub(crate) fn implement_primitive_str_accessor_derived(
     name: &syn::Ident,
-    value_type: &syn::Ident,
+    value_type: &syn::Type,
+    inline: InlineMode,
 ) -> TokenStream {
     quote! {
         impl #name {
+            #inline
             pub fn value(&self) -> &#value_type {
                 &self.0
             }
 
+            #inline
             pub fn primitive(&self) -> &str {
                 self.0.primitive()
             }
@@ -0,0 +1,69 @@
+use crate::detail::InlineMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates flag-set helpers and the named flag constants declared in
+/// `#[strong_type(bitflags(READ = 1, WRITE = 2))]`. The bitwise operators themselves come from the
+/// operator set. Immutable types leave out the in-place `insert`, `remove` and `toggle`.
+pub(crate) fn implement_bitflags(
+    name: &syn::Ident,
+    flags: &[(syn::Ident, syn::Expr)],
+    mutable: bool,
+    inline: InlineMode,
+) -> TokenStream {
+    let constants = flags.iter().map(|(flag, value)| {
+        quote! {
+            pub const #flag: Self = Self(#value);
+        }
+    });
+
+    let mutators = if mutable {
+        quote! {
+            /// Sets the bits of `other`
+            #inline
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            /// Clears the bits of `other`
+            #inline
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            /// Flips the bits of `other`
+            #inline
+            pub fn toggle(&mut self, other: Self) {
+                self.0 ^= other.0;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #name {
+            #(#constants)*
+
+            /// Returns `true` if all bits set in `other` are also set in `self`
+            #inline
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Returns `true` if `self` and `other` share at least one set bit
+            #inline
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            /// Returns `true` if no bits are set
+            #inline
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            #mutators
+        }
+    }
+}
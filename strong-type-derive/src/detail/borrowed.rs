@@ -0,0 +1,155 @@
+//! Borrowed companion types for string strong types.
+//!
+//! `#[strong_type(borrowed = TagStr)]` on `struct Tag(String)` generates an unsized
+//! `#[repr(transparent)] struct TagStr(str)` that relates to `Tag` the way `str` relates to
+//! `String`: `Tag` derefs and borrows to `TagStr`, and `TagStr` converts back via `ToOwned`.
+//! Equality, ordering and hashing are all computed over the same `str`, so a
+//! `HashMap<Tag, _>` can be queried with a `&TagStr` without allocating.
+
+use crate::detail::InlineMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn implement_borrowed(
+    name: &syn::Ident,
+    borrowed: &syn::Ident,
+    vis: &syn::Visibility,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    quote! {
+        #[repr(transparent)]
+        #vis struct #borrowed(str);
+
+        impl #borrowed {
+            #inline
+            pub const fn new(value: &str) -> &Self {
+                // SAFETY: the borrowed type is a `#[repr(transparent)]` wrapper around `str`.
+                unsafe { &*(value as *const str as *const Self) }
+            }
+
+            #inline
+            pub fn value(&self) -> &str {
+                &self.0
+            }
+
+            #inline
+            pub fn primitive(&self) -> &str {
+                self.value()
+            }
+        }
+
+        impl ::core::fmt::Debug for #borrowed {
+            #inline
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.debug_struct(::core::stringify!(#borrowed))
+                 .field("value", &&self.0)
+                 .finish()
+            }
+        }
+
+        impl ::core::fmt::Display for #borrowed {
+            #inline
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::write!(f, "{}({})", ::core::stringify!(#borrowed), &self.0)
+            }
+        }
+
+        impl ::core::cmp::PartialEq for #borrowed {
+            #inline
+            fn eq(&self, rhs: &Self) -> bool {
+                self.value() == rhs.value()
+            }
+        }
+
+        impl ::core::cmp::Eq for #borrowed {}
+
+        impl ::core::cmp::PartialOrd for #borrowed {
+            #inline
+            fn partial_cmp(&self, rhs: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, rhs))
+            }
+        }
+
+        impl ::core::cmp::Ord for #borrowed {
+            #inline
+            fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(self.value(), rhs.value())
+            }
+        }
+
+        impl ::core::hash::Hash for #borrowed {
+            #inline
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(self.value(), state);
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #borrowed {
+            #inline
+            fn as_ref(&self) -> &str {
+                self.value()
+            }
+        }
+
+        impl #crate_path::__private::ToOwned for #borrowed {
+            type Owned = #name;
+
+            #inline
+            fn to_owned(&self) -> #name {
+                #name::new(#crate_path::__private::ToOwned::to_owned(self.value()))
+            }
+        }
+
+        impl ::core::borrow::Borrow<#borrowed> for #name {
+            #inline
+            fn borrow(&self) -> &#borrowed {
+                #borrowed::new(self.value())
+            }
+        }
+
+        impl ::core::ops::Deref for #name {
+            type Target = #borrowed;
+
+            #inline
+            fn deref(&self) -> &#borrowed {
+                #borrowed::new(self.value())
+            }
+        }
+
+        impl ::core::convert::AsRef<#borrowed> for #name {
+            #inline
+            fn as_ref(&self) -> &#borrowed {
+                #borrowed::new(self.value())
+            }
+        }
+
+        impl ::core::convert::From<&#borrowed> for #name {
+            #inline
+            fn from(value: &#borrowed) -> Self {
+                #crate_path::__private::ToOwned::to_owned(value)
+            }
+        }
+
+        impl ::core::cmp::PartialEq<#borrowed> for #name {
+            #inline
+            fn eq(&self, rhs: &#borrowed) -> bool {
+                self.value() == rhs.value()
+            }
+        }
+
+        impl<'a> ::core::cmp::PartialEq<&'a #borrowed> for #name {
+            #inline
+            fn eq(&self, rhs: &&'a #borrowed) -> bool {
+                self.value() == rhs.value()
+            }
+        }
+
+        impl ::core::cmp::PartialEq<#name> for #borrowed {
+            #inline
+            fn eq(&self, rhs: &#name) -> bool {
+                self.value() == rhs.value()
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::detail::InlineMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the checked and wrapping shift methods of the primitive, returning the strong type.
+/// Like the primitive methods, the shift amount is a `u32`.
+pub(crate) fn implement_checked_shift(
+    name: &syn::Ident,
+    shl: bool,
+    shr: bool,
+    inline: InlineMode,
+) -> TokenStream {
+    let mut result = TokenStream::new();
+
+    if shl {
+        result.extend(quote! {
+            impl #name {
+                /// Shifts left, returning `None` if `rhs` is not less than the bit width
+                #inline
+                pub const fn checked_shl(self, rhs: u32) -> ::core::option::Option<Self> {
+                    match self.0.checked_shl(rhs) {
+                        ::core::option::Option::Some(value) => ::core::option::Option::Some(Self(value)),
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+
+                /// Shifts left, masking `rhs` to the bit width
+                #inline
+                pub const fn wrapping_shl(self, rhs: u32) -> Self {
+                    Self(self.0.wrapping_shl(rhs))
+                }
+            }
+        });
+    }
+
+    if shr {
+        result.extend(quote! {
+            impl #name {
+                /// Shifts right, returning `None` if `rhs` is not less than the bit width
+                #inline
+                pub const fn checked_shr(self, rhs: u32) -> ::core::option::Option<Self> {
+                    match self.0.checked_shr(rhs) {
+                        ::core::option::Option::Some(value) => ::core::option::Option::Some(Self(value)),
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+
+                /// Shifts right, masking `rhs` to the bit width
+                #inline
+                pub const fn wrapping_shr(self, rhs: u32) -> Self {
+                    Self(self.0.wrapping_shr(rhs))
+                }
+            }
+        });
+    }
+
+    result
+}
@@ -18,6 +18,7 @@
 //! - Centralized generation logic
 //! - Type-safe and maintainable
 
+use crate::detail::OperatorAttributes;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
@@ -42,6 +43,8 @@ pub struct BinaryOperatorSpec {
     pub assign_op_symbol: &'static str,
     /// Optional iterator trait to implement (e.g., Sum for Add, Product for Mul)
     pub iterator_trait: Option<&'static str>,
+    /// Whether the operator may panic on integers, so it reports the caller
+    pub may_panic: bool,
 }
 
 /// Specification for a unary operator (e.g., Neg, Not)
@@ -53,6 +56,8 @@ pub struct UnaryOperatorSpec {
     pub method: &'static str,
     /// The operator symbol (e.g., "-")
     pub op_symbol: &'static str,
+    /// Whether the operator may panic on integers, so it reports the caller
+    pub may_panic: bool,
 }
 
 /// Specification for scalar operations (Type * scalar)
@@ -72,6 +77,8 @@ pub struct ScalarOperatorSpec {
     pub assign_op_symbol: &'static str,
     /// Whether to generate commutative variant (scalar * Type)
     pub commutative: bool,
+    /// Whether the operator may panic on integers, so it reports the caller
+    pub may_panic: bool,
 }
 
 /// Mode for generating operators
@@ -108,6 +115,7 @@ pub mod binary_ops {
         assign_method: "add_assign",
         assign_op_symbol: "+=",
         iterator_trait: Some("Sum"),
+        may_panic: true,
     };
 
     pub const SUB: BinaryOperatorSpec = BinaryOperatorSpec {
@@ -118,6 +126,7 @@ pub mod binary_ops {
         assign_method: "sub_assign",
         assign_op_symbol: "-=",
         iterator_trait: None,
+        may_panic: true,
     };
 
     pub const MUL: BinaryOperatorSpec = BinaryOperatorSpec {
@@ -128,6 +137,7 @@ pub mod binary_ops {
         assign_method: "mul_assign",
         assign_op_symbol: "*=",
         iterator_trait: Some("Product"),
+        may_panic: true,
     };
 
     pub const DIV: BinaryOperatorSpec = BinaryOperatorSpec {
@@ -138,6 +148,7 @@ pub mod binary_ops {
         assign_method: "div_assign",
         assign_op_symbol: "/=",
         iterator_trait: None,
+        may_panic: true,
     };
 
     pub const REM: BinaryOperatorSpec = BinaryOperatorSpec {
@@ -148,6 +159,7 @@ pub mod binary_ops {
         assign_method: "rem_assign",
         assign_op_symbol: "%=",
         iterator_trait: None,
+        may_panic: true,
     };
 
     pub const BITAND: BinaryOperatorSpec = BinaryOperatorSpec {
@@ -158,6 +170,7 @@ pub mod binary_ops {
         assign_method: "bitand_assign",
         assign_op_symbol: "&=",
         iterator_trait: None,
+        may_panic: false,
     };
 
     pub const BITOR: BinaryOperatorSpec = BinaryOperatorSpec {
@@ -168,6 +181,7 @@ pub mod binary_ops {
         assign_method: "bitor_assign",
         assign_op_symbol: "|=",
         iterator_trait: None,
+        may_panic: false,
     };
 
     pub const BITXOR: BinaryOperatorSpec = BinaryOperatorSpec {
@@ -178,6 +192,7 @@ pub mod binary_ops {
         assign_method: "bitxor_assign",
         assign_op_symbol: "^=",
         iterator_trait: None,
+        may_panic: false,
     };
 }
 
@@ -189,19 +204,75 @@ pub mod unary_ops {
         trait_name: "Neg",
         method: "neg",
         op_symbol: "-",
+        may_panic: true,
     };
 
     pub const NOT: UnaryOperatorSpec = UnaryOperatorSpec {
         trait_name: "Not",
         method: "not",
         op_symbol: "!",
+        may_panic: false,
     };
 }
 
+/// Bit shift operators, generated once per integer right-hand side type
+pub mod shift_ops {
+    use super::BinaryOperatorSpec;
+
+    pub const SHL: BinaryOperatorSpec = BinaryOperatorSpec {
+        trait_name: "Shl",
+        method: "shl",
+        op_symbol: "<<",
+        assign_trait: "ShlAssign",
+        assign_method: "shl_assign",
+        assign_op_symbol: "<<=",
+        iterator_trait: None,
+        may_panic: true,
+    };
+
+    pub const SHR: BinaryOperatorSpec = BinaryOperatorSpec {
+        trait_name: "Shr",
+        method: "shr",
+        op_symbol: ">>",
+        assign_trait: "ShrAssign",
+        assign_method: "shr_assign",
+        assign_op_symbol: ">>=",
+        iterator_trait: None,
+        may_panic: true,
+    };
+
+    /// Right-hand side types accepted by shift operators
+    pub const SHIFT_TYPES: &[&str] = &[
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+}
+
 /// Scalar operators
 pub mod scalar_ops {
     use super::ScalarOperatorSpec;
 
+    pub const ADD: ScalarOperatorSpec = ScalarOperatorSpec {
+        trait_name: "Add",
+        method: "add",
+        op_symbol: "+",
+        assign_trait: "AddAssign",
+        assign_method: "add_assign",
+        assign_op_symbol: "+=",
+        commutative: true,
+        may_panic: true,
+    };
+
+    pub const SUB: ScalarOperatorSpec = ScalarOperatorSpec {
+        trait_name: "Sub",
+        method: "sub",
+        op_symbol: "-",
+        assign_trait: "SubAssign",
+        assign_method: "sub_assign",
+        assign_op_symbol: "-=",
+        commutative: false,
+        may_panic: true,
+    };
+
     pub const MUL: ScalarOperatorSpec = ScalarOperatorSpec {
         trait_name: "Mul",
         method: "mul",
@@ -210,6 +281,7 @@ pub mod scalar_ops {
         assign_method: "mul_assign",
         assign_op_symbol: "*=",
         commutative: true,
+        may_panic: true,
     };
 
     pub const DIV: ScalarOperatorSpec = ScalarOperatorSpec {
@@ -220,6 +292,7 @@ pub mod scalar_ops {
         assign_method: "div_assign",
         assign_op_symbol: "/=",
         commutative: false,
+        may_panic: true,
     };
 
     pub const REM: ScalarOperatorSpec = ScalarOperatorSpec {
@@ -230,6 +303,7 @@ pub mod scalar_ops {
         assign_method: "rem_assign",
         assign_op_symbol: "%=",
         commutative: false,
+        may_panic: true,
     };
 }
 
@@ -240,10 +314,11 @@ pub mod scalar_ops {
 /// Generates the StrongTypeOps trait implementation for delegation support
 pub fn generate_strong_type_ops_impl(
     name: &syn::Ident,
-    primitive_type: &syn::Ident,
+    primitive_type: &syn::Type,
+    crate_path: &syn::Path,
 ) -> TokenStream {
     quote! {
-        impl ::strong_type::delegation::StrongTypeOps for #name {
+        impl #crate_path::delegation::StrongTypeOps for #name {
             type Primitive = #primitive_type;
 
             #[inline(always)]
@@ -263,22 +338,16 @@ pub fn generate_strong_type_ops_impl(
 // Code Generators
 // ============================================================================
 
-/// Generates a binary operator implementation with all ownership variants
-pub fn generate_binary_operator(
-    name: &syn::Ident,
-    spec: &BinaryOperatorSpec,
-    mode: GenerationMode,
-) -> TokenStream {
-    generate_binary_operator_with_strategy(name, spec, mode, DelegationStrategy::Inline)
-}
-
 /// Generates a binary operator implementation with delegation strategy
 pub fn generate_binary_operator_with_strategy(
     name: &syn::Ident,
     spec: &BinaryOperatorSpec,
     mode: GenerationMode,
     strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
 ) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
     let trait_name = format_ident!("{}", spec.trait_name);
     let method = format_ident!("{}", spec.method);
     let assign_trait = format_ident!("{}", spec.assign_trait);
@@ -296,7 +365,7 @@ pub fn generate_binary_operator_with_strategy(
         }
         DelegationStrategy::Delegate => {
             let delegate_fn = format_ident!("delegate_{}", spec.method);
-            quote! { ::strong_type::delegation::#delegate_fn(self, rhs) }
+            quote! { #crate_path::delegation::#delegate_fn(self, rhs) }
         }
     };
 
@@ -308,41 +377,47 @@ pub fn generate_binary_operator_with_strategy(
         GenerationMode::Full => {
             // Generate all 4 ownership variants + assignment ops
             let mut result = quote! {
-                impl std::ops::#trait_name<Self> for #name {
+                impl ::core::ops::#trait_name<Self> for #name {
                     type Output = Self;
+                    #function_attributes
                     fn #method(self, rhs: Self) -> Self::Output {
                         #op_body_owned
                     }
                 }
 
-                impl std::ops::#trait_name<&Self> for #name {
+                impl ::core::ops::#trait_name<&Self> for #name {
                     type Output = Self;
+                    #function_attributes
                     fn #method(self, rhs: &Self) -> Self::Output {
                         #op_body_ref
                     }
                 }
 
-                impl<'a> std::ops::#trait_name<#name> for &'a #name {
+                impl<'a> ::core::ops::#trait_name<#name> for &'a #name {
                     type Output = #name;
+                    #function_attributes
                     fn #method(self, rhs: #name) -> Self::Output {
                         #ref_op_body
                     }
                 }
 
-                impl<'a> std::ops::#trait_name<&#name> for &'a #name {
+                impl<'a> ::core::ops::#trait_name<&#name> for &'a #name {
                     type Output = #name;
+                    #function_attributes
                     fn #method(self, rhs: &#name) -> Self::Output {
                         #ref_op_body
                     }
                 }
 
-                impl std::ops::#assign_trait<Self> for #name {
+                impl ::core::ops::#assign_trait<Self> for #name {
+                    #function_attributes
                     fn #assign_method(&mut self, rhs: Self) {
                         self.0 #assign_op_symbol rhs.value()
                     }
                 }
 
-                impl std::ops::#assign_trait<&Self> for #name {
+                impl ::core::ops::#assign_trait<&Self> for #name {
+                    #function_attributes
                     fn #assign_method(&mut self, rhs: &Self) {
                         self.0 #assign_op_symbol rhs.value()
                     }
@@ -351,27 +426,15 @@ pub fn generate_binary_operator_with_strategy(
 
             // Add iterator traits if specified
             if let Some(iterator_trait_name) = spec.iterator_trait {
-                let iterator_trait = format_ident!("{}", iterator_trait_name);
-                let iterator_method = format_ident!("{}", iterator_trait_name.to_lowercase());
-                let neutral_element = if iterator_trait_name == "Sum" {
-                    quote! { Self::ZERO }
-                } else {
-                    quote! { Self::ONE }
-                };
-
-                result.extend(quote! {
-                    impl std::iter::#iterator_trait<Self> for #name {
-                        fn #iterator_method<I: Iterator<Item = Self>>(iter: I) -> Self {
-                            iter.fold(#neutral_element, std::ops::#trait_name::#method)
-                        }
-                    }
-
-                    impl<'a> std::iter::#iterator_trait<&'a Self> for #name {
-                        fn #iterator_method<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-                            iter.fold(#neutral_element, std::ops::#trait_name::#method)
-                        }
-                    }
-                });
+                result.extend(generate_iterator_traits(
+                    name,
+                    spec,
+                    iterator_trait_name,
+                    GenerationMode::Full,
+                    strategy,
+                    crate_path,
+                    attributes,
+                ));
             }
 
             result
@@ -379,14 +442,16 @@ pub fn generate_binary_operator_with_strategy(
         GenerationMode::Minimal => {
             // Generate basic impl + assignment ops + iterator traits (but no reference variants)
             let mut result = quote! {
-                impl std::ops::#trait_name<Self> for #name {
+                impl ::core::ops::#trait_name<Self> for #name {
                     type Output = Self;
+                    #function_attributes
                     fn #method(self, rhs: Self) -> Self::Output {
                         #op_body_owned
                     }
                 }
 
-                impl std::ops::#assign_trait<Self> for #name {
+                impl ::core::ops::#assign_trait<Self> for #name {
+                    #function_attributes
                     fn #assign_method(&mut self, rhs: Self) {
                         self.0 #assign_op_symbol rhs.value()
                     }
@@ -395,21 +460,15 @@ pub fn generate_binary_operator_with_strategy(
 
             // Add iterator traits if specified
             if let Some(iterator_trait_name) = spec.iterator_trait {
-                let iterator_trait = format_ident!("{}", iterator_trait_name);
-                let iterator_method = format_ident!("{}", iterator_trait_name.to_lowercase());
-                let neutral_element = if iterator_trait_name == "Sum" {
-                    quote! { Self::ZERO }
-                } else {
-                    quote! { Self::ONE }
-                };
-
-                result.extend(quote! {
-                    impl std::iter::#iterator_trait<Self> for #name {
-                        fn #iterator_method<I: Iterator<Item = Self>>(iter: I) -> Self {
-                            iter.fold(#neutral_element, std::ops::#trait_name::#method)
-                        }
-                    }
-                });
+                result.extend(generate_iterator_traits(
+                    name,
+                    spec,
+                    iterator_trait_name,
+                    GenerationMode::Minimal,
+                    strategy,
+                    crate_path,
+                    attributes,
+                ));
             }
 
             result
@@ -417,13 +476,138 @@ pub fn generate_binary_operator_with_strategy(
     }
 }
 
-/// Generates a unary operator implementation
-pub fn generate_unary_operator(
+/// Generates `Sum`/`Product` over owned and borrowed items by folding with the operator
+fn generate_iterator_traits(
     name: &syn::Ident,
-    spec: &UnaryOperatorSpec,
+    spec: &BinaryOperatorSpec,
+    iterator_trait_name: &str,
     mode: GenerationMode,
+    strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
 ) -> TokenStream {
-    generate_unary_operator_with_strategy(name, spec, mode, DelegationStrategy::Inline)
+    let inline = attributes.inline;
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let iterator_trait = format_ident!("{}", iterator_trait_name);
+    let iterator_method = format_ident!("{}", iterator_trait_name.to_lowercase());
+    let neutral_element = if iterator_trait_name == "Sum" {
+        quote! { Self::ZERO }
+    } else {
+        quote! { Self::ONE }
+    };
+
+    let (owned_body, borrowed_body) = match (strategy, mode) {
+        (DelegationStrategy::Delegate, _) => {
+            let delegate_fn = format_ident!("delegate_{}", iterator_method);
+            (
+                quote! { #crate_path::delegation::#delegate_fn(&mut { iter }) },
+                quote! { #crate_path::delegation::#delegate_fn(&mut ::core::iter::Iterator::copied(iter)) },
+            )
+        }
+        (DelegationStrategy::Inline, GenerationMode::Full) => (
+            quote! { ::core::iter::Iterator::fold(iter, #neutral_element, ::core::ops::#trait_name::#method) },
+            quote! { ::core::iter::Iterator::fold(iter, #neutral_element, ::core::ops::#trait_name::#method) },
+        ),
+        // Folding borrowed items reuses the owned operator, so `Sum<&Self>` stays cheap
+        (DelegationStrategy::Inline, GenerationMode::Minimal) => (
+            quote! { ::core::iter::Iterator::fold(iter, #neutral_element, ::core::ops::#trait_name::#method) },
+            quote! { ::core::iter::Iterator::fold(::core::iter::Iterator::copied(iter), #neutral_element, ::core::ops::#trait_name::#method) },
+        ),
+    };
+
+    quote! {
+        impl ::core::iter::#iterator_trait<Self> for #name {
+            #inline
+            fn #iterator_method<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                #owned_body
+            }
+        }
+
+        impl<'a> ::core::iter::#iterator_trait<&'a Self> for #name {
+            #inline
+            fn #iterator_method<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
+                #borrowed_body
+            }
+        }
+    }
+}
+
+/// Generates a binary operator between two different strong types (e.g., Point + Delta)
+///
+/// All four ownership variants are generated. The assignment operator is only generated when
+/// the output type is the left-hand side type.
+pub fn generate_mixed_binary_operator(
+    lhs: &syn::Ident,
+    rhs: &syn::Ident,
+    output: &syn::Ident,
+    spec: &BinaryOperatorSpec,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let assign_trait = format_ident!("{}", spec.assign_trait);
+    let assign_method = format_ident!("{}", spec.assign_method);
+
+    let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
+    let assign_op_symbol = syn::parse_str::<TokenStream>(spec.assign_op_symbol).unwrap();
+
+    let op_body = quote! { #output::new(self.value() #op_symbol rhs.value()) };
+
+    let mut result = quote! {
+        impl ::core::ops::#trait_name<#rhs> for #lhs {
+            type Output = #output;
+            #function_attributes
+            fn #method(self, rhs: #rhs) -> Self::Output {
+                #op_body
+            }
+        }
+
+        impl<'a> ::core::ops::#trait_name<&'a #rhs> for #lhs {
+            type Output = #output;
+            #function_attributes
+            fn #method(self, rhs: &'a #rhs) -> Self::Output {
+                #op_body
+            }
+        }
+
+        impl<'a> ::core::ops::#trait_name<#rhs> for &'a #lhs {
+            type Output = #output;
+            #function_attributes
+            fn #method(self, rhs: #rhs) -> Self::Output {
+                #op_body
+            }
+        }
+
+        impl<'a, 'b> ::core::ops::#trait_name<&'b #rhs> for &'a #lhs {
+            type Output = #output;
+            #function_attributes
+            fn #method(self, rhs: &'b #rhs) -> Self::Output {
+                #op_body
+            }
+        }
+    };
+
+    if lhs == output {
+        result.extend(quote! {
+            impl ::core::ops::#assign_trait<#rhs> for #lhs {
+                #function_attributes
+                fn #assign_method(&mut self, rhs: #rhs) {
+                    self.0 #assign_op_symbol rhs.value()
+                }
+            }
+
+            impl<'a> ::core::ops::#assign_trait<&'a #rhs> for #lhs {
+                #function_attributes
+                fn #assign_method(&mut self, rhs: &'a #rhs) {
+                    self.0 #assign_op_symbol rhs.value()
+                }
+            }
+        });
+    }
+
+    result
 }
 
 /// Generates a unary operator implementation with delegation strategy
@@ -432,7 +616,10 @@ pub fn generate_unary_operator_with_strategy(
     spec: &UnaryOperatorSpec,
     mode: GenerationMode,
     strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
 ) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
     let trait_name = format_ident!("{}", spec.trait_name);
     let method = format_ident!("{}", spec.method);
     let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
@@ -444,7 +631,7 @@ pub fn generate_unary_operator_with_strategy(
         }
         DelegationStrategy::Delegate => {
             let delegate_fn = format_ident!("delegate_{}", spec.method);
-            quote! { ::strong_type::delegation::#delegate_fn(self) }
+            quote! { #crate_path::delegation::#delegate_fn(self) }
         }
     };
 
@@ -453,15 +640,17 @@ pub fn generate_unary_operator_with_strategy(
     match mode {
         GenerationMode::Full => {
             quote! {
-                impl std::ops::#trait_name for #name {
+                impl ::core::ops::#trait_name for #name {
                     type Output = Self;
+                    #function_attributes
                     fn #method(self) -> Self::Output {
                         #op_body
                     }
                 }
 
-                impl<'a> std::ops::#trait_name for &'a #name {
+                impl<'a> ::core::ops::#trait_name for &'a #name {
                     type Output = #name;
+                    #function_attributes
                     fn #method(self) -> Self::Output {
                         #ref_op_body
                     }
@@ -470,8 +659,9 @@ pub fn generate_unary_operator_with_strategy(
         }
         GenerationMode::Minimal => {
             quote! {
-                impl std::ops::#trait_name for #name {
+                impl ::core::ops::#trait_name for #name {
                     type Output = Self;
+                    #function_attributes
                     fn #method(self) -> Self::Output {
                         #op_body
                     }
@@ -481,22 +671,17 @@ pub fn generate_unary_operator_with_strategy(
     }
 }
 
-/// Generates scalar operator implementations (Type * scalar, scalar * Type)
-pub fn generate_scalar_operator(
-    name: &syn::Ident,
-    value_type: &syn::Ident,
-    spec: &ScalarOperatorSpec,
-) -> TokenStream {
-    generate_scalar_operator_with_strategy(name, value_type, spec, DelegationStrategy::Inline)
-}
-
-/// Generates scalar operator implementations with delegation strategy
+/// Generates scalar operator implementations (Type * scalar, scalar * Type) with delegation strategy
 pub fn generate_scalar_operator_with_strategy(
     name: &syn::Ident,
-    value_type: &syn::Ident,
+    value_type: &syn::Type,
     spec: &ScalarOperatorSpec,
+    mode: GenerationMode,
     strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
 ) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
     let trait_name = format_ident!("{}", spec.trait_name);
     let method = format_ident!("{}", spec.method);
     let assign_trait = format_ident!("{}", spec.assign_trait);
@@ -512,7 +697,7 @@ pub fn generate_scalar_operator_with_strategy(
         }
         DelegationStrategy::Delegate => {
             let delegate_fn = format_ident!("delegate_scalar_{}", spec.method);
-            quote! { ::strong_type::delegation::#delegate_fn(self, rhs) }
+            quote! { #crate_path::delegation::#delegate_fn(self, rhs) }
         }
     };
 
@@ -521,215 +706,287 @@ pub fn generate_scalar_operator_with_strategy(
     let comm_ref_body = quote! { #name(self #op_symbol rhs.0) };
 
     let mut result = quote! {
-        impl std::ops::#trait_name<#value_type> for #name {
+        impl ::core::ops::#trait_name<#value_type> for #name {
             type Output = Self;
+            #function_attributes
             fn #method(self, rhs: #value_type) -> Self::Output {
                 #op_body
             }
         }
 
-        impl<'a> std::ops::#trait_name<#value_type> for &'a #name {
-            type Output = #name;
-            fn #method(self, rhs: #value_type) -> Self::Output {
-                #ref_op_body
-            }
-        }
-
-        impl std::ops::#assign_trait<#value_type> for #name {
+        impl ::core::ops::#assign_trait<#value_type> for #name {
+            #function_attributes
             fn #assign_method(&mut self, rhs: #value_type) {
                 self.0 #assign_op_symbol rhs;
             }
         }
     };
 
+    if mode == GenerationMode::Full {
+        result.extend(quote! {
+            impl<'a> ::core::ops::#trait_name<#value_type> for &'a #name {
+                type Output = #name;
+                #function_attributes
+                fn #method(self, rhs: #value_type) -> Self::Output {
+                    #ref_op_body
+                }
+            }
+        });
+    }
+
     // Add commutative variant if specified
     if spec.commutative {
         result.extend(quote! {
-            impl std::ops::#trait_name<#name> for #value_type {
+            impl ::core::ops::#trait_name<#name> for #value_type {
                 type Output = #name;
+                #function_attributes
                 fn #method(self, rhs: #name) -> Self::Output {
                     #comm_body
                 }
             }
+        });
 
-            impl<'a> std::ops::#trait_name<&#name> for #value_type {
-                type Output = #name;
-                fn #method(self, rhs: &#name) -> Self::Output {
-                    #comm_ref_body
+        if mode == GenerationMode::Full {
+            result.extend(quote! {
+                impl<'a> ::core::ops::#trait_name<&#name> for #value_type {
+                    type Output = #name;
+                    #function_attributes
+                    fn #method(self, rhs: &#name) -> Self::Output {
+                        #comm_ref_body
+                    }
                 }
-            }
-        });
+            });
+        }
     }
 
     result
 }
 
-// ============================================================================
-// High-Level Feature Generators
-// ============================================================================
+/// Generates an operator between a strong type and an item count (e.g., `Type / usize`), which
+/// is converted to the wrapped primitive first
+pub fn generate_count_operator(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    spec: &ScalarOperatorSpec,
+    mode: GenerationMode,
+    strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let assign_trait = format_ident!("{}", spec.assign_trait);
+    let assign_method = format_ident!("{}", spec.assign_method);
 
-/// Generates arithmetic operators (Add, Sub, Mul, Div, Rem) for a type
-pub fn generate_arithmetic_operators(name: &syn::Ident, mode: GenerationMode) -> TokenStream {
-    let mut result = TokenStream::new();
+    let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
+    let assign_op_symbol = syn::parse_str::<TokenStream>(spec.assign_op_symbol).unwrap();
 
-    result.extend(generate_binary_operator(name, &binary_ops::ADD, mode));
-    result.extend(generate_binary_operator(name, &binary_ops::SUB, mode));
-    result.extend(generate_binary_operator(name, &binary_ops::MUL, mode));
-    result.extend(generate_binary_operator(name, &binary_ops::DIV, mode));
-    result.extend(generate_binary_operator(name, &binary_ops::REM, mode));
+    let op_body = match strategy {
+        DelegationStrategy::Inline => {
+            quote! { Self::new(self.value() #op_symbol rhs as #value_type) }
+        }
+        DelegationStrategy::Delegate => {
+            let delegate_fn = format_ident!("delegate_scalar_{}", spec.method);
+            quote! { #crate_path::delegation::#delegate_fn(self, rhs as #value_type) }
+        }
+    };
 
-    result
-}
+    let mut result = quote! {
+        impl ::core::ops::#trait_name<usize> for #name {
+            type Output = Self;
+            #function_attributes
+            fn #method(self, rhs: usize) -> Self::Output {
+                #op_body
+            }
+        }
 
-/// Generates addable operators (Add, Sub) for a type
-pub fn generate_addable_operators(name: &syn::Ident, mode: GenerationMode) -> TokenStream {
-    let mut result = TokenStream::new();
+        impl ::core::ops::#assign_trait<usize> for #name {
+            #function_attributes
+            fn #assign_method(&mut self, rhs: usize) {
+                self.0 #assign_op_symbol rhs as #value_type;
+            }
+        }
+    };
 
-    result.extend(generate_binary_operator(name, &binary_ops::ADD, mode));
-    result.extend(generate_binary_operator(name, &binary_ops::SUB, mode));
+    if mode == GenerationMode::Full {
+        result.extend(quote! {
+            impl<'a> ::core::ops::#trait_name<usize> for &'a #name {
+                type Output = #name;
+                #function_attributes
+                fn #method(self, rhs: usize) -> Self::Output {
+                    #name::new(self.value() #op_symbol rhs as #value_type)
+                }
+            }
+        });
+    }
 
     result
 }
 
+// ============================================================================
+// High-Level Feature Generators
+// ============================================================================
+
 /// Generates scalar multiplication and division operators
-pub fn generate_scalable_operators(name: &syn::Ident, value_type: &syn::Ident) -> TokenStream {
+pub fn generate_scalable_operators(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
     let mut result = TokenStream::new();
 
-    result.extend(generate_scalar_operator(name, value_type, &scalar_ops::MUL));
-    result.extend(generate_scalar_operator(name, value_type, &scalar_ops::DIV));
-    result.extend(generate_scalar_operator(name, value_type, &scalar_ops::REM));
+    for spec in [&scalar_ops::MUL, &scalar_ops::DIV, &scalar_ops::REM] {
+        result.extend(generate_scalar_operator_with_strategy(
+            name,
+            value_type,
+            spec,
+            GenerationMode::Full,
+            strategy,
+            crate_path,
+            attributes,
+        ));
+    }
 
     result
 }
 
-/// Generates bit operators (BitAnd, BitOr, BitXor) for a type
-pub fn generate_bit_operators(name: &syn::Ident, mode: GenerationMode) -> TokenStream {
+/// Generates scalar addition and subtraction operators (Type + primitive, Type - primitive)
+pub fn generate_addable_primitive_operators(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    mode: GenerationMode,
+    strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
     let mut result = TokenStream::new();
 
-    result.extend(generate_binary_operator(name, &binary_ops::BITAND, mode));
-    result.extend(generate_binary_operator(name, &binary_ops::BITOR, mode));
-    result.extend(generate_binary_operator(name, &binary_ops::BITXOR, mode));
+    result.extend(generate_scalar_operator_with_strategy(
+        name,
+        value_type,
+        &scalar_ops::ADD,
+        mode,
+        strategy,
+        crate_path,
+        attributes,
+    ));
+    result.extend(generate_scalar_operator_with_strategy(
+        name,
+        value_type,
+        &scalar_ops::SUB,
+        mode,
+        strategy,
+        crate_path,
+        attributes,
+    ));
 
     result
 }
 
-/// Generates negation operator
-pub fn generate_negation(name: &syn::Ident, mode: GenerationMode) -> TokenStream {
-    generate_unary_operator(name, &unary_ops::NEG, mode)
-}
-
-/// Generates logical not operator
-pub fn generate_not(name: &syn::Ident, mode: GenerationMode) -> TokenStream {
-    generate_unary_operator(name, &unary_ops::NOT, mode)
-}
-
-/// Generates boolean operators (Not, BitAnd, BitOr, BitXor) for bool types
-pub fn generate_bool_operators(name: &syn::Ident, mode: GenerationMode) -> TokenStream {
+/// Generates affine operators between a point type and its delta type:
+/// `Point - Point = Delta`, `Point ± Delta = Point` and `Delta + Point = Point`
+pub fn generate_point_operators(
+    point: &syn::Ident,
+    delta: &syn::Ident,
+    attributes: OperatorAttributes,
+) -> TokenStream {
     let mut result = TokenStream::new();
 
-    result.extend(generate_not(name, mode));
-    result.extend(generate_bit_operators(name, mode));
+    result.extend(generate_mixed_binary_operator(
+        point,
+        point,
+        delta,
+        &binary_ops::SUB,
+        attributes,
+    ));
+    result.extend(generate_mixed_binary_operator(
+        point,
+        delta,
+        point,
+        &binary_ops::ADD,
+        attributes,
+    ));
+    result.extend(generate_mixed_binary_operator(
+        point,
+        delta,
+        point,
+        &binary_ops::SUB,
+        attributes,
+    ));
+    result.extend(generate_mixed_binary_operator(
+        delta,
+        point,
+        point,
+        &binary_ops::ADD,
+        attributes,
+    ));
 
     result
 }
 
-/// Generates bit shift operations (Shl, Shr) for a specific shift type
-fn generate_bit_shift_for_type(name: &syn::Ident, shift_type: &syn::Ident) -> TokenStream {
-    generate_bit_shift_for_type_with_strategy(name, shift_type, DelegationStrategy::Inline)
-}
-
-/// Generates bit shift operations with delegation strategy
-pub(crate) fn generate_bit_shift_for_type_with_strategy(
+/// Generates a bit shift operator (Shl or Shr) for a specific right-hand side type
+pub fn generate_shift_operator(
     name: &syn::Ident,
+    spec: &BinaryOperatorSpec,
     shift_type: &syn::Ident,
+    mode: GenerationMode,
     strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
 ) -> TokenStream {
-    // Determine the operation body based on delegation strategy
-    let shl_body = match strategy {
-        DelegationStrategy::Inline => {
-            quote! { Self::new(self.value() << rhs) }
-        }
-        DelegationStrategy::Delegate => {
-            quote! { ::strong_type::delegation::delegate_shl(self, rhs) }
-        }
-    };
+    let function_attributes = attributes.function(spec.may_panic);
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let assign_trait = format_ident!("{}", spec.assign_trait);
+    let assign_method = format_ident!("{}", spec.assign_method);
 
-    let shl_ref_body = quote! { #name::new(self.value() << rhs) };
+    let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
+    let assign_op_symbol = syn::parse_str::<TokenStream>(spec.assign_op_symbol).unwrap();
 
-    let shr_body = match strategy {
+    // Determine the operation body based on delegation strategy
+    let op_body = match strategy {
         DelegationStrategy::Inline => {
-            quote! { Self::new(self.value() >> rhs) }
+            quote! { Self::new(self.value() #op_symbol rhs) }
         }
         DelegationStrategy::Delegate => {
-            quote! { ::strong_type::delegation::delegate_shr(self, rhs) }
+            let delegate_fn = format_ident!("delegate_{}", spec.method);
+            quote! { #crate_path::delegation::#delegate_fn(self, rhs) }
         }
     };
 
-    let shr_ref_body = quote! { #name::new(self.value() >> rhs) };
+    let ref_op_body = quote! { #name::new(self.value() #op_symbol rhs) };
 
-    quote! {
-        impl std::ops::Shl<#shift_type> for #name {
-            type Output = Self;
-            fn shl(self, rhs: #shift_type) -> Self::Output {
-                #shl_body
-            }
-        }
-
-        impl std::ops::ShlAssign<#shift_type> for #name {
-            fn shl_assign(&mut self, rhs: #shift_type) {
-                self.0 <<= rhs;
-            }
-        }
-
-        impl std::ops::Shr<#shift_type> for #name {
+    let mut result = quote! {
+        impl ::core::ops::#trait_name<#shift_type> for #name {
             type Output = Self;
-            fn shr(self, rhs: #shift_type) -> Self::Output {
-                #shr_body
-            }
-        }
-
-        impl std::ops::ShrAssign<#shift_type> for #name {
-            fn shr_assign(&mut self, rhs: #shift_type) {
-                self.0 >>= rhs;
+            #function_attributes
+            fn #method(self, rhs: #shift_type) -> Self::Output {
+                #op_body
             }
         }
 
-        impl std::ops::Shl<#shift_type> for &#name {
-            type Output = #name;
-            fn shl(self, rhs: #shift_type) -> Self::Output {
-                #shl_ref_body
+        impl ::core::ops::#assign_trait<#shift_type> for #name {
+            #function_attributes
+            fn #assign_method(&mut self, rhs: #shift_type) {
+                self.0 #assign_op_symbol rhs;
             }
         }
+    };
 
-        impl std::ops::Shr<#shift_type> for &#name {
-            type Output = #name;
-            fn shr(self, rhs: #shift_type) -> Self::Output {
-                #shr_ref_body
+    if mode == GenerationMode::Full {
+        result.extend(quote! {
+            impl ::core::ops::#trait_name<#shift_type> for &#name {
+                type Output = #name;
+                #function_attributes
+                fn #method(self, rhs: #shift_type) -> Self::Output {
+                    #ref_op_body
+                }
             }
-        }
-    }
-}
-
-/// Generates bit shift operations for all integer types
-pub fn generate_bit_shift_operators(name: &syn::Ident) -> TokenStream {
-    const SHIFT_TYPES: &[&str] = &[
-        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
-    ];
-
-    let mut result = TokenStream::new();
-    for type_str in SHIFT_TYPES {
-        let shift_type = format_ident!("{}", type_str);
-        result.extend(generate_bit_shift_for_type(name, &shift_type));
+        });
     }
-    result
-}
-
-/// Generates all bit operations (shifts + bitwise) for integer types
-pub fn generate_all_bit_operations(name: &syn::Ident, mode: GenerationMode) -> TokenStream {
-    let mut result = TokenStream::new();
-
-    result.extend(generate_bit_shift_operators(name));
-    result.extend(generate_bit_operators(name, mode));
 
     result
 }
@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-pub(crate) fn implement_constants(name: &syn::Ident, value_type: &syn::Ident) -> TokenStream {
+pub(crate) fn implement_constants(name: &syn::Ident, value_type: &syn::Type) -> TokenStream {
     quote! {
         impl #name {
             pub const ZERO: Self = Self(0 as #value_type);
@@ -12,30 +12,30 @@ pub(crate) fn implement_constants(name: &syn::Ident, value_type: &syn::Ident) ->
 
 pub(crate) fn implement_constants_derived(
     name: &syn::Ident,
-    value_type: &syn::Ident,
+    value_type: &syn::Type,
 ) -> TokenStream {
     quote! {
         impl #name {
-            pub const ZERO: Self = Self(#value_type::ZERO);
-            pub const ONE: Self = Self(#value_type::ONE);
+            pub const ZERO: Self = Self(<#value_type>::ZERO);
+            pub const ONE: Self = Self(<#value_type>::ONE);
         }
     }
 }
 
-pub(crate) fn implement_infinity(name: &syn::Ident, value_type: &syn::Ident) -> TokenStream {
+pub(crate) fn implement_infinity(name: &syn::Ident, value_type: &syn::Type) -> TokenStream {
     quote! {
         impl #name {
-            pub const INFINITY: Self = Self(#value_type::INFINITY);
-            pub const NEG_INFINITY: Self = Self(#value_type::NEG_INFINITY);
+            pub const INFINITY: Self = Self(<#value_type>::INFINITY);
+            pub const NEG_INFINITY: Self = Self(<#value_type>::NEG_INFINITY);
         }
     }
 }
 
-pub(crate) fn implement_limit(name: &syn::Ident, value_type: &syn::Ident) -> TokenStream {
+pub(crate) fn implement_limit(name: &syn::Ident, value_type: &syn::Type) -> TokenStream {
     quote! {
         impl #name {
-            pub const MIN: Self = Self(#value_type::MIN);
-            pub const MAX: Self = Self(#value_type::MAX);
+            pub const MIN: Self = Self(<#value_type>::MIN);
+            pub const MAX: Self = Self(<#value_type>::MAX);
         }
     }
 }
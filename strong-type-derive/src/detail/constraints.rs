@@ -0,0 +1,212 @@
+//! Constraints on string strong types: `min_len`, `max_len`, `charset` and `pattern`.
+//!
+//! Constrained types get a `validate` function, a fallible `try_new` constructor and `FromStr`.
+//! Lengths and charsets are checked with plain code, while patterns are compiled once into a
+//! `regex::Regex` re-exported by `strong_type` behind its `regex` feature.
+
+use crate::detail::InlineMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A set of allowed characters, written like the inside of a regex class, e.g. `a-z0-9_-`
+pub(crate) struct Charset {
+    pub literal: syn::LitStr,
+    /// Inclusive character ranges; single characters are ranges of length one
+    pub ranges: Vec<(char, char)>,
+}
+
+impl Charset {
+    pub fn parse(literal: syn::LitStr) -> Result<Self, syn::Error> {
+        let mut characters = Vec::new();
+        let mut chars = literal.value().chars().collect::<Vec<_>>().into_iter();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    let escaped = chars.next().ok_or_else(|| {
+                        syn::Error::new_spanned(&literal, "charset ends with an unfinished escape.")
+                    })?;
+                    characters.push((escaped, true));
+                }
+                c => characters.push((c, false)),
+            }
+        }
+
+        let mut ranges = Vec::new();
+        let mut index = 0;
+        while index < characters.len() {
+            let (start, _) = characters[index];
+            match (characters.get(index + 1), characters.get(index + 2)) {
+                (Some(('-', false)), Some(&(end, _))) => {
+                    if start > end {
+                        return Err(syn::Error::new_spanned(
+                            &literal,
+                            format!("Invalid charset range '{}-{}'.", start, end),
+                        ));
+                    }
+                    ranges.push((start, end));
+                    index += 3;
+                }
+                _ => {
+                    ranges.push((start, start));
+                    index += 1;
+                }
+            }
+        }
+
+        if ranges.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &literal,
+                "charset must not be empty.",
+            ));
+        }
+        Ok(Self { literal, ranges })
+    }
+}
+
+pub(crate) struct StringConstraints {
+    pub min_len: Option<usize>,
+    pub max_len: Option<usize>,
+    pub charset: Option<Charset>,
+    pub pattern: Option<syn::LitStr>,
+}
+
+impl StringConstraints {
+    /// Checks a value known at compile time against the length and charset constraints, with the
+    /// same reasons as the generated `validate`. Patterns are only checked at runtime.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        let len = value.chars().count();
+        if let Some(min_len) = self.min_len
+            && len < min_len
+        {
+            return Err(format!("must be at least {} characters long", min_len));
+        }
+        if let Some(max_len) = self.max_len
+            && len > max_len
+        {
+            return Err(format!("must be at most {} characters long", max_len));
+        }
+        if let Some(charset) = &self.charset
+            && let Some(c) = value.chars().find(|c| {
+                !charset
+                    .ranges
+                    .iter()
+                    .any(|(start, end)| (start..=end).contains(&c))
+            })
+        {
+            return Err(format!(
+                "contains {:?}, which is not in the charset '{}'",
+                c,
+                charset.literal.value()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Generates `validate`, `try_new` and `FromStr` for a constrained string type. Normalization,
+/// if any, is applied before validation. `FromStr` is left out with a restricted constructor,
+/// since it would construct values publicly.
+pub(crate) fn implement_constraints(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    constraints: &StringConstraints,
+    normalized: bool,
+    constructor_vis: &syn::Visibility,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    let mut checks = TokenStream::new();
+
+    if constraints.min_len.is_some() || constraints.max_len.is_some() {
+        checks.extend(quote! {
+            let len = ::core::iter::Iterator::count(value.chars());
+        });
+    }
+    if let Some(min_len) = constraints.min_len {
+        checks.extend(quote! {
+            if len < #min_len {
+                return ::core::result::Result::Err(error(#crate_path::__private::format!("must be at least {} characters long", #min_len)));
+            }
+        });
+    }
+    if let Some(max_len) = constraints.max_len {
+        checks.extend(quote! {
+            if len > #max_len {
+                return ::core::result::Result::Err(error(#crate_path::__private::format!("must be at most {} characters long", #max_len)));
+            }
+        });
+    }
+    if let Some(charset) = &constraints.charset {
+        let literal = &charset.literal;
+        let ranges = charset.ranges.iter().map(|(start, end)| {
+            if start == end {
+                quote! { #start }
+            } else {
+                quote! { #start..=#end }
+            }
+        });
+        checks.extend(quote! {
+            if let ::core::option::Option::Some(c) = ::core::iter::Iterator::find(&mut value.chars(), |c| !::core::matches!(c, #(#ranges)|*)) {
+                return ::core::result::Result::Err(error(#crate_path::__private::format!("contains {:?}, which is not in the charset '{}'", c, #literal)));
+            }
+        });
+    }
+    if let Some(pattern) = &constraints.pattern {
+        let anchored = format!("^(?:{})$", pattern.value());
+        checks.extend(quote! {
+            static PATTERN: #crate_path::__private::LazyLock<#crate_path::__private::Regex> =
+                #crate_path::__private::LazyLock::new(|| #crate_path::__private::Regex::new(#anchored).unwrap());
+            if !PATTERN.is_match(value) {
+                return ::core::result::Result::Err(error(#crate_path::__private::format!("does not match the pattern '{}'", #pattern)));
+            }
+        });
+    }
+
+    let normalize = if normalized {
+        quote! { let value: #value_type = ::core::convert::Into::into(Self::normalize(&value)); }
+    } else {
+        quote! {}
+    };
+
+    let from_str = if matches!(constructor_vis, syn::Visibility::Public(_)) {
+        quote! {
+            impl ::core::str::FromStr for #name {
+                type Err = #crate_path::Error;
+
+                #inline
+                fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                    Self::try_new(#crate_path::__private::ToOwned::to_owned(value))
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #name {
+            /// Checks a string against the constraints of this type
+            #inline
+            pub fn validate(value: &str) -> ::core::result::Result<(), #crate_path::Error> {
+                let error = |reason: #crate_path::__private::String| {
+                    #crate_path::Error::constraint(::core::stringify!(#name), value, reason)
+                };
+                #checks
+                ::core::result::Result::Ok(())
+            }
+
+            /// Creates a value, or returns an error if it violates the constraints of this type
+            #inline
+            #constructor_vis fn try_new(
+                value: impl ::core::convert::Into<#value_type>,
+            ) -> ::core::result::Result<Self, #crate_path::Error> {
+                let value: #value_type = ::core::convert::Into::into(value);
+                #normalize
+                Self::validate(&value)?;
+                ::core::result::Result::Ok(Self(value))
+            }
+        }
+
+        #from_str
+    }
+}
@@ -1,49 +1,146 @@
+use crate::detail::InlineMode;
 use proc_macro2::TokenStream;
 use quote::quote;
 
-pub(crate) fn implement_conversion(name: &syn::Ident, value_type: &syn::Ident) -> TokenStream {
-    quote! {
-        impl From<#value_type> for #name {
-            fn from(value: #value_type) -> Self {
-                Self::new(value)
+/// The conversions into a strong type. Validated types go through `try_new` and implement
+/// `TryFrom` instead, since `From` must not fail.
+struct Conversion<'a> {
+    name: &'a syn::Ident,
+    validated: bool,
+    crate_path: &'a syn::Path,
+    inline: InlineMode,
+}
+
+impl Conversion<'_> {
+    /// Converts `source` into the strong type
+    fn convert(
+        &self,
+        generics: TokenStream,
+        source: TokenStream,
+        where_clause: TokenStream,
+        body: TokenStream,
+    ) -> TokenStream {
+        let Self {
+            name,
+            validated,
+            crate_path,
+            inline,
+        } = self;
+        if *validated {
+            quote! {
+                impl #generics ::core::convert::TryFrom<#source> for #name #where_clause {
+                    type Error = #crate_path::Error;
+
+                    #inline
+                    fn try_from(value: #source) -> ::core::result::Result<Self, Self::Error> {
+                        Self::try_new(#body)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #generics ::core::convert::From<#source> for #name #where_clause {
+                    #inline
+                    fn from(value: #source) -> Self {
+                        Self::new(#body)
+                    }
+                }
             }
         }
+    }
+}
 
-        impl From<#name> for #value_type {
-            fn from(value: #name) -> #value_type {
-                value.0
+pub(crate) fn implement_conversion(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    mutable: bool,
+    validated: bool,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    let conversion = Conversion {
+        name,
+        validated,
+        crate_path,
+        inline,
+    };
+    let from_mut = if mutable {
+        quote! {
+            impl<'a> ::core::convert::From<&'a mut #name> for &'a mut #value_type {
+                #inline
+                fn from(value: &'a mut #name) -> Self {
+                    value.as_mut()
+                }
             }
         }
+    } else {
+        quote! {}
+    };
+
+    let from_value = conversion.convert(quote!(), quote!(#value_type), quote!(), quote!(value));
+    let from_ref = conversion.convert(
+        quote!(<'a>),
+        quote!(&'a #value_type),
+        quote!(where for<'b> #value_type: ::core::clone::Clone),
+        quote!(::core::clone::Clone::clone(value)),
+    );
+
+    quote! {
+        #from_value
 
-        impl<'a> From<&'a #value_type> for #name
-        where
-            #value_type: Clone,
-        {
-            fn from(value: &'a #value_type) -> Self {
-                Self::new(value.clone())
+        impl ::core::convert::From<#name> for #value_type {
+            #inline
+            fn from(value: #name) -> #value_type {
+                value.0
             }
         }
 
-        impl<'a> From<&'a #name> for &'a #value_type {
+        #from_ref
+
+        impl<'a> ::core::convert::From<&'a #name> for &'a #value_type {
+            #inline
             fn from(value: &'a #name) -> Self {
                 value.as_ref()
             }
         }
 
-        impl<'a> From<&'a mut #name> for &'a mut #value_type {
-            fn from(value: &'a mut #name) -> Self {
-                value.as_mut()
-            }
-        }
+        #from_mut
     }
 }
 
-pub(crate) fn implement_str_conversion(name: &syn::Ident) -> TokenStream {
-    quote! {
-        impl From<&str> for #name {
-            fn from(value: &str) -> Self {
-                Self::new(value)
-            }
-        }
-    }
+pub(crate) fn implement_str_conversion(
+    name: &syn::Ident,
+    validated: bool,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    let conversion = Conversion {
+        name,
+        validated,
+        crate_path,
+        inline,
+    };
+    conversion.convert(quote!(), quote!(&str), quote!(), quote!(value))
+}
+
+pub(crate) fn implement_static_str_conversion(
+    name: &syn::Ident,
+    validated: bool,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    let conversion = Conversion {
+        name,
+        validated,
+        crate_path,
+        inline,
+    };
+    let mut result = conversion.convert(quote!(), quote!(&'static str), quote!(), quote!(value));
+    result.extend(conversion.convert(
+        quote!(),
+        quote!(#crate_path::__private::String),
+        quote!(),
+        quote!(value),
+    ));
+    result
 }
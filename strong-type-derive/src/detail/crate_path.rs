@@ -0,0 +1,15 @@
+//! Support for `#[strong_type(crate = "...")]`.
+//!
+//! Generators refer to the runtime crate through a `#crate_path` variable, which is
+//! `::strong_type` unless the crate is re-exported through a facade.
+
+/// The path to the runtime crate without `crate = "..."`
+pub(crate) fn default_crate_path() -> syn::Path {
+    syn::parse_quote!(::strong_type)
+}
+
+/// Parses `crate = "path"`, naming the path under which strong_type is re-exported
+pub(crate) fn parse_crate_path(meta: &syn::meta::ParseNestedMeta) -> Result<syn::Path, syn::Error> {
+    let literal: syn::LitStr = meta.value()?.parse()?;
+    literal.parse()
+}
@@ -0,0 +1,61 @@
+use crate::detail::InlineMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The value returned by the generated `Default` impl
+pub(crate) enum DefaultValue<'a> {
+    /// The default of the wrapped value, if it has one. The bound is higher-ranked so that a
+    /// wrapped strong type without `Default` leaves the impl out instead of failing to compile.
+    Inner,
+    /// The `default = <expr>` attribute; `is_const` when the value can be built in a const
+    /// context, which also generates a `DEFAULT` constant
+    Expr { expr: &'a syn::Expr, is_const: bool },
+}
+
+pub(crate) fn implement_default(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    default: DefaultValue,
+    inline: InlineMode,
+) -> TokenStream {
+    match default {
+        DefaultValue::Inner => quote! {
+            impl ::core::default::Default for #name
+            where
+                for<'a> #value_type: ::core::default::Default,
+            {
+                #inline
+                fn default() -> Self {
+                    Self::new(<#value_type>::default())
+                }
+            }
+        },
+        DefaultValue::Expr {
+            expr,
+            is_const: true,
+        } => quote! {
+            impl #name {
+                /// The value returned by `Default::default`
+                pub const DEFAULT: Self = Self(#expr);
+            }
+
+            impl ::core::default::Default for #name {
+                #inline
+                fn default() -> Self {
+                    Self::DEFAULT
+                }
+            }
+        },
+        DefaultValue::Expr {
+            expr,
+            is_const: false,
+        } => quote! {
+            impl ::core::default::Default for #name {
+                #inline
+                fn default() -> Self {
+                    Self::new(#expr)
+                }
+            }
+        },
+    }
+}
@@ -1,11 +1,13 @@
+use crate::detail::InlineMode;
 use proc_macro2::TokenStream;
 use quote::quote;
 
-pub(crate) fn implement_display(name: &syn::Ident) -> TokenStream {
+pub(crate) fn implement_display(name: &syn::Ident, inline: InlineMode) -> TokenStream {
     quote! {
-        impl std::fmt::Display for #name {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(f, "{}({})", stringify!(#name), &self.0)
+        impl ::core::fmt::Display for #name {
+            #inline
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::write!(f, "{}({})", ::core::stringify!(#name), &self.0)
             }
         }
     }
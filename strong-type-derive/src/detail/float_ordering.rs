@@ -0,0 +1,35 @@
+use crate::detail::InlineMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Floats only implement `PartialOrd`, so `Ord::min`/`Ord::max` are unavailable on float strong
+/// types. These helpers follow the primitive semantics, where NaN is ignored if the other value
+/// is a number. `clamp` checks its bounds itself, since the primitive `clamp` would report the
+/// panic inside the standard library rather than at the caller.
+pub(crate) fn implement_float_min_max(name: &syn::Ident, inline: InlineMode) -> TokenStream {
+    quote! {
+        impl #name {
+            #inline
+            pub fn min(self, other: Self) -> Self {
+                Self::new(self.value().min(other.value()))
+            }
+
+            #inline
+            pub fn max(self, other: Self) -> Self {
+                Self::new(self.value().max(other.value()))
+            }
+
+            #inline
+            #[track_caller]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                ::core::assert!(
+                    min.value() <= max.value(),
+                    "min > max, or either was NaN. min = {:?}, max = {:?}",
+                    min.value(),
+                    max.value(),
+                );
+                Self::new(self.value().clamp(min.value(), max.value()))
+            }
+        }
+    }
+}
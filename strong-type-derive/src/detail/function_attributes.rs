@@ -0,0 +1,37 @@
+//! Attributes applied uniformly to the functions of the generated impls.
+//!
+//! Generators emit the `inline` setting before every function through the `ToTokens` impl of
+//! [`InlineMode`]. The operator templates in `codegen_framework` also add `#[track_caller]` to
+//! the operators that may panic, so panics point to user code.
+
+use crate::detail::InlineMode;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+
+impl ToTokens for InlineMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            InlineMode::Default => quote! {},
+            InlineMode::Always => quote! { #[inline(always)] },
+            InlineMode::Never => quote! { #[inline(never)] },
+        });
+    }
+}
+
+/// The attributes of generated operator functions
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OperatorAttributes {
+    pub inline: InlineMode,
+    /// Whether the arithmetic of the wrapped type may panic on overflow, division by zero or
+    /// oversized shifts, as integer arithmetic does
+    pub track_caller: bool,
+}
+
+impl OperatorAttributes {
+    /// The attributes of an operator function, with `#[track_caller]` if the operator may panic
+    pub(crate) fn function(self, may_panic: bool) -> TokenStream {
+        let inline = self.inline;
+        let track_caller = (self.track_caller && may_panic).then(|| quote! { #[track_caller] });
+        quote! { #inline #track_caller }
+    }
+}
@@ -1,20 +1,50 @@
+use crate::detail::{InlineMode, SkippableTrait, unless_skipped};
 use proc_macro2::TokenStream;
 use quote::quote;
+use std::collections::BTreeSet;
+
+pub(crate) fn implement_hash(
+    name: &syn::Ident,
+    skipped: &BTreeSet<SkippableTrait>,
+    inline: InlineMode,
+) -> TokenStream {
+    let eq = unless_skipped(
+        skipped,
+        SkippableTrait::Eq,
+        quote! {
+            impl ::core::cmp::Eq for #name {}
+        },
+    );
+    let ord = unless_skipped(
+        skipped,
+        SkippableTrait::Ord,
+        quote! {
+            impl ::core::cmp::Ord for #name {
+                #inline
+                fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ord::cmp(&self.value(), &rhs.value())
+                }
+            }
+        },
+    );
+    let hash = unless_skipped(
+        skipped,
+        SkippableTrait::Hash,
+        quote! {
+            impl ::core::hash::Hash for #name {
+                #inline
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(&self.value(), state);
+                }
+            }
+        },
+    );
 
-pub(crate) fn implement_hash(name: &syn::Ident) -> TokenStream {
     quote! {
-        impl std::cmp::Eq for #name {}
+        #eq
 
-        impl std::cmp::Ord for #name {
-            fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
-                self.value().cmp(&rhs.value())
-            }
-        }
+        #ord
 
-        impl std::hash::Hash for #name {
-            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-                self.value().hash(state);
-            }
-       }
+        #hash
     }
 }
@@ -0,0 +1,254 @@
+use crate::detail::{BorrowedKind, LifetimeMetadata, SkippableTrait, unless_skipped};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The type a strong type with a lifetime parameter borrows: `str` or `[u8]`
+fn borrowed_target(kind: BorrowedKind) -> syn::Type {
+    match kind {
+        BorrowedKind::Str | BorrowedKind::CowStr => syn::parse_quote!(str),
+        BorrowedKind::Bytes => syn::parse_quote!([u8]),
+    }
+}
+
+/// Generates a strong type wrapping `&'a str`, `&'a [u8]` or `Cow<'a, str>`. References are
+/// `Copy` and hand out values that outlive the strong type, while `Cow` values are only
+/// borrowed for as long as the strong type.
+pub(crate) fn implement_lifetime(metadata: &LifetimeMetadata) -> TokenStream {
+    let name = &metadata.name;
+    let lifetime = &metadata.lifetime;
+    let value_type = &metadata.value_type;
+    let target = borrowed_target(metadata.kind);
+    let crate_path = &metadata.crate_path;
+
+    let mut result = match metadata.kind {
+        BorrowedKind::Str | BorrowedKind::Bytes => quote! {
+            impl<#lifetime> #name<#lifetime> {
+                pub const fn new(value: #value_type) -> Self {
+                    Self(value)
+                }
+
+                pub const fn value(&self) -> #value_type {
+                    self.0
+                }
+
+                pub const fn primitive(&self) -> #value_type {
+                    self.0
+                }
+            }
+
+            impl<#lifetime> #crate_path::StrongTypeAccess for #name<#lifetime> {
+                type Value<'__access> = Self where Self: '__access;
+                type Primitive<'__access> = #value_type where Self: '__access;
+
+                fn as_value(&self) -> Self::Value<'_> {
+                    *self
+                }
+
+                fn as_primitive(&self) -> Self::Primitive<'_> {
+                    self.0
+                }
+            }
+
+            impl<#lifetime> ::core::marker::Copy for #name<#lifetime> {}
+
+            impl<#lifetime> ::core::clone::Clone for #name<#lifetime> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        },
+        BorrowedKind::CowStr => quote! {
+            impl<#lifetime> #name<#lifetime> {
+                pub fn new(value: impl ::core::convert::Into<#value_type>) -> Self {
+                    Self(::core::convert::Into::into(value))
+                }
+
+                pub fn value(&self) -> &str {
+                    &self.0
+                }
+
+                pub fn primitive(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl<#lifetime> #crate_path::StrongTypeAccess for #name<#lifetime> {
+                type Value<'__access> = &'__access Self where Self: '__access;
+                type Primitive<'__access> = &'__access str where Self: '__access;
+
+                fn as_value(&self) -> Self::Value<'_> {
+                    self
+                }
+
+                fn as_primitive(&self) -> Self::Primitive<'_> {
+                    &self.0
+                }
+            }
+
+            impl<#lifetime> ::core::clone::Clone for #name<#lifetime> {
+                fn clone(&self) -> Self {
+                    Self(::core::clone::Clone::clone(&self.0))
+                }
+            }
+        },
+    };
+
+    let into_owned = metadata.owned_type.as_ref().map(|owned_type| {
+        let owned_value = match metadata.kind {
+            BorrowedKind::Str | BorrowedKind::Bytes => {
+                quote!(#crate_path::__private::ToOwned::to_owned(self.0))
+            }
+            BorrowedKind::CowStr => quote!(#crate_path::__private::Cow::into_owned(self.0)),
+        };
+        quote! {
+            /// Converts the borrowed value into the owned strong type
+            pub fn into_owned(self) -> #owned_type {
+                #owned_type::new(#owned_value)
+            }
+        }
+    });
+
+    result.extend(quote! {
+        impl<#lifetime> #name<#lifetime> {
+            pub fn into_inner(self) -> #value_type {
+                self.0
+            }
+
+            #into_owned
+        }
+
+        impl<#lifetime> #crate_path::StrongType for #name<#lifetime> {
+            type UnderlyingType = #value_type;
+            type PrimitiveType = #value_type;
+        }
+    });
+
+    let skipped = &metadata.skipped_traits;
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Debug,
+        quote! {
+            impl<#lifetime> ::core::fmt::Debug for #name<#lifetime> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    f.debug_struct(::core::stringify!(#name))
+                     .field("value", &self.0)
+                     .finish()
+                }
+            }
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Default,
+        quote! {
+            impl<#lifetime> ::core::default::Default for #name<#lifetime> {
+                fn default() -> Self {
+                    Self(::core::default::Default::default())
+                }
+            }
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::PartialEq,
+        quote! {
+            impl<#lifetime> ::core::cmp::PartialEq for #name<#lifetime> {
+                fn eq(&self, rhs: &Self) -> bool {
+                    self.value() == rhs.value()
+                }
+            }
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Eq,
+        quote! {
+            impl<#lifetime> ::core::cmp::Eq for #name<#lifetime> {}
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::PartialOrd,
+        quote! {
+            impl<#lifetime> ::core::cmp::PartialOrd for #name<#lifetime> {
+                fn partial_cmp(&self, rhs: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, rhs))
+                }
+            }
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Ord,
+        quote! {
+            impl<#lifetime> ::core::cmp::Ord for #name<#lifetime> {
+                fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ord::cmp(self.value(), rhs.value())
+                }
+            }
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Hash,
+        quote! {
+            impl<#lifetime> ::core::hash::Hash for #name<#lifetime> {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(self.value(), state);
+                }
+            }
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::AsRef,
+        quote! {
+            impl<#lifetime> ::core::convert::AsRef<#target> for #name<#lifetime> {
+                fn as_ref(&self) -> &#target {
+                    &self.0
+                }
+            }
+        },
+    ));
+
+    if metadata.kind != BorrowedKind::Bytes {
+        result.extend(unless_skipped(
+            skipped,
+            SkippableTrait::Display,
+            quote! {
+                impl<#lifetime> ::core::fmt::Display for #name<#lifetime> {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::write!(f, "{}({})", ::core::stringify!(#name), self.value())
+                    }
+                }
+            },
+        ));
+    }
+
+    if metadata.has_conversion {
+        result.extend(quote! {
+            impl<#lifetime> ::core::convert::From<#value_type> for #name<#lifetime> {
+                fn from(value: #value_type) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl<#lifetime> ::core::convert::From<#name<#lifetime>> for #value_type {
+                fn from(value: #name<#lifetime>) -> #value_type {
+                    value.0
+                }
+            }
+        });
+        if metadata.kind == BorrowedKind::CowStr {
+            result.extend(quote! {
+                impl<#lifetime> ::core::convert::From<&#lifetime str> for #name<#lifetime> {
+                    fn from(value: &#lifetime str) -> Self {
+                        Self::new(value)
+                    }
+                }
+            });
+        }
+    }
+
+    result
+}
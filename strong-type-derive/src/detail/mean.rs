@@ -0,0 +1,109 @@
+use super::codegen_framework::{generate_count_operator, scalar_ops};
+use crate::detail::{
+    AutoOperatorMode, InlineMode, OperatorAttributes, ValueTypeGroup, generation_settings,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `mean` for addable numeric types.
+///
+/// Integers up to 64 bits are accumulated in 128 bits, so neither the running sum nor the item
+/// count can overflow; the result is truncated towards zero like integer division. `i128` and
+/// `u128` have no wider type to accumulate in, so their sum is checked instead.
+pub(crate) fn implement_mean(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    primitive_type: &syn::Type,
+    type_group: &ValueTypeGroup,
+    inline: InlineMode,
+) -> TokenStream {
+    // The value type may be an alias, so the width comes from the resolved primitive
+    if is_128_bit(primitive_type) {
+        return quote! {
+            impl #name {
+                /// Returns the arithmetic mean of the values, or `None` if the iterator is empty
+                /// or the sum of the values overflows.
+                #inline
+                pub fn mean<I, B>(iter: I) -> ::core::option::Option<Self>
+                where
+                    I: ::core::iter::IntoIterator<Item = B>,
+                    B: ::core::borrow::Borrow<Self>,
+                {
+                    let mut sum: #value_type = 0;
+                    let mut count = 0usize;
+                    for item in iter {
+                        sum = <#value_type>::checked_add(sum, ::core::borrow::Borrow::<Self>::borrow(&item).value())?;
+                        count += 1;
+                    }
+                    if count == 0 {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(Self::new(sum / count as #value_type))
+                    }
+                }
+            }
+        };
+    }
+
+    let accumulator = match type_group {
+        ValueTypeGroup::Int(_) => quote!(i128),
+        ValueTypeGroup::UInt(_) => quote!(u128),
+        _ => quote!(#value_type),
+    };
+
+    quote! {
+        impl #name {
+            /// Returns the arithmetic mean of the values, or `None` if the iterator is empty.
+            #inline
+            pub fn mean<I, B>(iter: I) -> ::core::option::Option<Self>
+            where
+                I: ::core::iter::IntoIterator<Item = B>,
+                B: ::core::borrow::Borrow<Self>,
+            {
+                let (sum, count) = ::core::iter::Iterator::fold(
+                    ::core::iter::IntoIterator::into_iter(iter),
+                    (0 as #accumulator, 0usize),
+                    |(sum, count), item| (sum + ::core::borrow::Borrow::<Self>::borrow(&item).value() as #accumulator, count + 1),
+                );
+                if count == 0 {
+                    ::core::option::Option::None
+                } else {
+                    ::core::option::Option::Some(Self::new((sum / count as #accumulator) as #value_type))
+                }
+            }
+        }
+    }
+}
+
+fn is_128_bit(primitive_type: &syn::Type) -> bool {
+    match primitive_type {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "i128" || segment.ident == "u128"),
+        _ => false,
+    }
+}
+
+/// Generates division of a float strong type by an item count, without the full `scalable`
+/// surface, following the ownership variants and delegation strategy of the `auto_operators`
+/// mode.
+pub(crate) fn implement_count_division(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    auto_operator_mode: AutoOperatorMode,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let (mode, strategy) = generation_settings(auto_operator_mode);
+    generate_count_operator(
+        name,
+        value_type,
+        &scalar_ops::DIV,
+        mode,
+        strategy,
+        crate_path,
+        attributes,
+    )
+}
@@ -1,51 +1,83 @@
-mod addable;
-mod arithmetic;
+mod addable_primitive;
 mod basic;
 mod basic_primitive;
 mod basic_string;
-mod bit_ops;
-mod bool_ops;
+mod bitflags;
+mod borrowed;
+mod checked_shift;
 mod codegen_framework;
 mod constants;
+mod constraints;
 mod conversion;
-mod delegated_operators;
+mod crate_path;
+mod default;
 mod display;
+mod float_ordering;
+mod function_attributes;
 mod hash;
-mod minimal_operators;
+mod lifetime;
+mod mean;
 mod nan;
-mod negate;
+mod nested;
+mod nested_operators;
+mod normalize;
+mod opaque;
+mod operators;
+mod point;
 mod scalable;
+mod skip;
+mod string_collection;
+mod suggest;
+mod tagged;
 mod underlying_type_utils;
 mod utils;
 
-pub(crate) use addable::implement_addable;
-pub(crate) use arithmetic::implement_arithmetic;
-pub(crate) use basic::implement_basic;
+pub(crate) use addable_primitive::implement_addable_primitive;
+pub(crate) use basic::{Access, Constructor, implement_basic};
 pub(crate) use basic_primitive::{
     implement_basic_primitive, implement_primitive_accessor, implement_primitive_accessor_derived,
 };
 pub(crate) use basic_string::{
     implement_basic_string, implement_primitive_str_accessor,
-    implement_primitive_str_accessor_derived,
+    implement_primitive_str_accessor_derived, implement_static_str_constructor,
 };
-pub(crate) use bit_ops::implement_bit_shift;
-pub(crate) use bool_ops::implement_bool_ops;
+pub(crate) use bitflags::implement_bitflags;
+pub(crate) use borrowed::implement_borrowed;
+pub(crate) use checked_shift::implement_checked_shift;
 pub(crate) use codegen_framework::generate_strong_type_ops_impl;
 pub(crate) use constants::{
     implement_constants, implement_constants_derived, implement_infinity, implement_limit,
 };
-pub(crate) use conversion::{implement_conversion, implement_str_conversion};
-pub(crate) use delegated_operators::{
-    implement_delegated_arithmetic, implement_delegated_bit_shift, implement_delegated_bool_ops,
-    implement_delegated_negate, implement_delegated_scalable,
+pub(crate) use constraints::{Charset, StringConstraints, implement_constraints};
+pub(crate) use conversion::{
+    implement_conversion, implement_static_str_conversion, implement_str_conversion,
 };
+pub(crate) use crate_path::{default_crate_path, parse_crate_path};
+pub(crate) use default::{DefaultValue, implement_default};
 pub(crate) use display::implement_display;
+pub(crate) use float_ordering::implement_float_min_max;
+pub(crate) use function_attributes::OperatorAttributes;
 pub(crate) use hash::implement_hash;
-pub(crate) use minimal_operators::{
-    impl_minimal_negate, implement_minimal_arithmetic, implement_minimal_bool_ops,
-};
+pub(crate) use lifetime::implement_lifetime;
+pub(crate) use mean::{implement_count_division, implement_mean};
 pub(crate) use nan::implement_nan;
-pub(crate) use negate::implement_negate;
+pub(crate) use nested::{implement_bounded_traits, implement_nested, implement_strong_type_access};
+pub(crate) use nested_operators::implement_nested_operators;
+pub(crate) use normalize::implement_normalize;
+pub(crate) use opaque::implement_opaque;
+pub(crate) use operators::{
+    Operator, OperatorSet, generation_settings, implement_operators, shift_types,
+};
+pub(crate) use point::implement_point;
 pub(crate) use scalable::implement_scalable;
-pub(crate) use underlying_type_utils::{TypeInfo, UnderlyingType, ValueTypeGroup, get_type};
-pub(crate) use utils::{AutoOperatorMode, TypeMetadata, validate_struct};
+pub(crate) use skip::{SkippableTrait, unless_skipped};
+pub(crate) use string_collection::implement_string_collection;
+pub(crate) use suggest::{closest, did_you_mean};
+pub(crate) use tagged::implement_tagged;
+pub(crate) use underlying_type_utils::{
+    BorrowedKind, TypeInfo, UnderlyingType, ValueTypeGroup, get_borrowed_kind, get_type,
+};
+pub(crate) use utils::{
+    AutoOperatorMode, InlineMode, LifetimeMetadata, Normalization, TaggedDisplay, TaggedMetadata,
+    TypeMetadata, validate_struct,
+};
@@ -1,15 +1,22 @@
+use crate::detail::InlineMode;
 use proc_macro2::TokenStream;
 use quote::quote;
 
-pub(crate) fn implement_nan(name: &syn::Ident, value_type: &syn::Ident) -> TokenStream {
+pub(crate) fn implement_nan(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    inline: InlineMode,
+) -> TokenStream {
     quote! {
         impl #name {
-            pub const NAN: Self = Self(#value_type::NAN);
+            pub const NAN: Self = Self(<#value_type>::NAN);
 
+            #inline
             pub fn is_nan(&self) -> bool {
                 self.0.is_nan()
             }
 
+            #inline
             pub fn is_finite(&self) -> bool {
                 self.0.is_finite()
             }
@@ -0,0 +1,192 @@
+//! Strong types wrapping another strong type without an `underlying` attribute.
+//!
+//! The primitive type of the inner strong type is unknown during expansion, so the generated
+//! code reaches it through the `StrongType` and `StrongTypeAccess` impls of the inner type.
+//! Traits that the inner type may lack are bounded on it. The bounds are higher-ranked so that
+//! they are only checked on use, as a plain bound on a concrete type must hold.
+
+use crate::detail::{InlineMode, SkippableTrait, ValueTypeGroup, unless_skipped};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+
+/// How `StrongTypeAccess` hands out the value and the primitive of a strong type
+pub(crate) fn implement_strong_type_access(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    primitive_type: &syn::Type,
+    type_group: &ValueTypeGroup,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    let (value, as_value, primitive) = match type_group {
+        ValueTypeGroup::Int(_)
+        | ValueTypeGroup::UInt(_)
+        | ValueTypeGroup::Float(_)
+        | ValueTypeGroup::Bool(_)
+        | ValueTypeGroup::Char(_) => (quote!(Self), quote!(*self), quote!(#primitive_type)),
+        ValueTypeGroup::String(_) | ValueTypeGroup::CowStr => {
+            (quote!(&'a Self), quote!(self), quote!(&'a str))
+        }
+        ValueTypeGroup::Nested => (
+            quote!(&'a Self),
+            quote!(self),
+            quote!(<#value_type as #crate_path::StrongTypeAccess>::Primitive<'a>),
+        ),
+        ValueTypeGroup::Opaque => (quote!(&'a Self), quote!(self), quote!(&'a #value_type)),
+    };
+
+    // Like `StrongType`, only implemented for opaque types whose wrapped type allows it
+    let bound = if matches!(type_group, ValueTypeGroup::Opaque) {
+        quote! {
+            where
+                for<'a> #value_type: ::core::fmt::Debug
+                    + ::core::cmp::PartialEq
+                    + ::core::cmp::PartialOrd
+                    + ::core::clone::Clone,
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #crate_path::StrongTypeAccess for #name #bound {
+            type Value<'a> = #value;
+            type Primitive<'a> = #primitive;
+
+            #inline
+            fn as_value(&self) -> Self::Value<'_> {
+                #as_value
+            }
+
+            #inline
+            fn as_primitive(&self) -> Self::Primitive<'_> {
+                #name::primitive(self)
+            }
+        }
+    }
+}
+
+pub(crate) fn implement_nested(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    skipped: &BTreeSet<SkippableTrait>,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    let mut result = quote! {
+        impl #name {
+            #inline
+            pub fn value(&self) -> <#value_type as #crate_path::StrongTypeAccess>::Value<'_> {
+                #crate_path::StrongTypeAccess::as_value(&self.0)
+            }
+
+            #inline
+            pub fn primitive(
+                &self,
+            ) -> <#value_type as #crate_path::StrongTypeAccess>::Primitive<'_> {
+                #crate_path::StrongTypeAccess::as_primitive(&self.0)
+            }
+        }
+    };
+    result.extend(implement_bounded_traits(name, value_type, skipped, inline));
+    result
+}
+
+/// Implements the traits that depend on the wrapped type, each bounded on the wrapped type
+/// implementing it. A wrapped strong type always has `Clone` and `PartialOrd`, while an opaque
+/// type may lack them.
+pub(crate) fn implement_bounded_traits(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    skipped: &BTreeSet<SkippableTrait>,
+    inline: InlineMode,
+) -> TokenStream {
+    let mut result = quote! {
+        impl ::core::clone::Clone for #name
+        where
+            for<'a> #value_type: ::core::clone::Clone,
+        {
+            #inline
+            fn clone(&self) -> Self {
+                Self(::core::clone::Clone::clone(&self.0))
+            }
+        }
+
+        impl ::core::marker::Copy for #name where for<'a> #value_type: ::core::marker::Copy {}
+    };
+
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::PartialOrd,
+        quote! {
+            impl ::core::cmp::PartialOrd for #name
+            where
+                for<'a> #value_type: ::core::cmp::PartialOrd,
+            {
+                #inline
+                fn partial_cmp(&self, rhs: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::cmp::PartialOrd::partial_cmp(&self.0, &rhs.0)
+                }
+            }
+        },
+    ));
+
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Eq,
+        quote! {
+            impl ::core::cmp::Eq for #name where for<'a> #value_type: ::core::cmp::Eq {}
+        },
+    ));
+
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Ord,
+        quote! {
+            impl ::core::cmp::Ord for #name
+            where
+                for<'a> #value_type: ::core::cmp::Ord,
+            {
+                #inline
+                fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ord::cmp(&self.0, &rhs.0)
+                }
+            }
+        },
+    ));
+
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Hash,
+        quote! {
+            impl ::core::hash::Hash for #name
+            where
+                for<'a> #value_type: ::core::hash::Hash,
+            {
+                #inline
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(&self.0, state);
+                }
+            }
+        },
+    ));
+
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Display,
+        quote! {
+            impl ::core::fmt::Display for #name
+            where
+                for<'a> #value_type: ::core::fmt::Display,
+            {
+                #inline
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::write!(f, "{}({})", ::core::stringify!(#name), &self.0)
+                }
+            }
+        },
+    ));
+
+    result
+}
@@ -0,0 +1,531 @@
+//! Operators of strong types wrapping another strong type without an `underlying` attribute.
+//!
+//! The primitive type of the inner strong type is unknown during expansion, so each operator
+//! forwards to the same operator of the inner type, bounded on the inner type implementing it.
+//! As in `nested`, the bounds are higher-ranked or mention a lifetime of the impl so that they
+//! are only checked on use. Delegated modes forward as well, since the inner type decides how
+//! its own operators are generated.
+
+use super::codegen_framework::{
+    BinaryOperatorSpec, GenerationMode, ScalarOperatorSpec, UnaryOperatorSpec, binary_ops,
+    scalar_ops, shift_ops, unary_ops,
+};
+use crate::detail::{Operator, OperatorAttributes, TypeMetadata, generation_settings};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+pub(crate) fn implement_nested_operators(
+    metadata: &TypeMetadata,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let name = &metadata.name;
+    let inner = &metadata.value_type;
+    let crate_path = &metadata.crate_path;
+    let (mode, _) = generation_settings(metadata.auto_operator_mode);
+
+    let shifts = |spec: &BinaryOperatorSpec| -> TokenStream {
+        metadata
+            .shift_types
+            .iter()
+            .map(|shift_type| {
+                let shift_type: syn::Type = syn::parse_quote!(#shift_type);
+                shift(name, inner, spec, &shift_type, mode, attributes)
+            })
+            .collect()
+    };
+
+    let mut result = TokenStream::new();
+    for operator in Operator::ALL {
+        if !metadata.operators.contains(operator) {
+            continue;
+        }
+        result.extend(match operator {
+            Operator::Neg => unary(name, inner, &unary_ops::NEG, mode, attributes),
+            Operator::Not => unary(name, inner, &unary_ops::NOT, mode, attributes),
+            Operator::Add => binary(name, inner, &binary_ops::ADD, mode, attributes),
+            Operator::Sub => binary(name, inner, &binary_ops::SUB, mode, attributes),
+            Operator::Mul => binary(name, inner, &binary_ops::MUL, mode, attributes),
+            Operator::Div => binary(name, inner, &binary_ops::DIV, mode, attributes),
+            Operator::Rem => binary(name, inner, &binary_ops::REM, mode, attributes),
+            Operator::BitAnd => binary(name, inner, &binary_ops::BITAND, mode, attributes),
+            Operator::BitOr => binary(name, inner, &binary_ops::BITOR, mode, attributes),
+            Operator::BitXor => binary(name, inner, &binary_ops::BITXOR, mode, attributes),
+            Operator::Shl => shifts(&shift_ops::SHL),
+            Operator::Shr => shifts(&shift_ops::SHR),
+        });
+    }
+
+    // Scalars are values of the primitive type, which the inner type must accept
+    let primitive: syn::Type =
+        syn::parse_quote!(<#inner as #crate_path::StrongType>::PrimitiveType);
+    if metadata.has_addable_primitive {
+        for spec in [&scalar_ops::ADD, &scalar_ops::SUB] {
+            result.extend(scalar(name, inner, &primitive, spec, mode, attributes));
+        }
+    }
+    if metadata.has_scalable {
+        for spec in [&scalar_ops::MUL, &scalar_ops::DIV, &scalar_ops::REM] {
+            result.extend(scalar(
+                name,
+                inner,
+                &primitive,
+                spec,
+                GenerationMode::Full,
+                attributes,
+            ));
+        }
+    }
+
+    if let Some(delta) = &metadata.delta_type {
+        result.extend(point(name, inner, delta, attributes));
+    }
+
+    result
+}
+
+fn binary(
+    name: &syn::Ident,
+    inner: &syn::Type,
+    spec: &BinaryOperatorSpec,
+    mode: GenerationMode,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let assign_trait = format_ident!("{}", spec.assign_trait);
+    let assign_method = format_ident!("{}", spec.assign_method);
+    let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
+    let assign_op_symbol = syn::parse_str::<TokenStream>(spec.assign_op_symbol).unwrap();
+
+    let mut result = quote! {
+        impl ::core::ops::#trait_name<Self> for #name
+        where
+            for<'a> #inner: ::core::ops::#trait_name<Output = #inner>,
+        {
+            type Output = Self;
+            #function_attributes
+            fn #method(self, rhs: Self) -> Self::Output {
+                Self(self.0 #op_symbol rhs.0)
+            }
+        }
+
+        impl ::core::ops::#assign_trait<Self> for #name
+        where
+            for<'a> #inner: ::core::ops::#assign_trait,
+        {
+            #function_attributes
+            fn #assign_method(&mut self, rhs: Self) {
+                self.0 #assign_op_symbol rhs.0
+            }
+        }
+    };
+
+    if mode == GenerationMode::Full {
+        result.extend(quote! {
+            impl<'a> ::core::ops::#trait_name<&'a Self> for #name
+            where
+                #inner: ::core::ops::#trait_name<&'a #inner, Output = #inner>,
+            {
+                type Output = Self;
+                #function_attributes
+                fn #method(self, rhs: &'a Self) -> Self::Output {
+                    Self(self.0 #op_symbol &rhs.0)
+                }
+            }
+
+            impl<'a> ::core::ops::#trait_name<#name> for &'a #name
+            where
+                &'a #inner: ::core::ops::#trait_name<#inner, Output = #inner>,
+            {
+                type Output = #name;
+                #function_attributes
+                fn #method(self, rhs: #name) -> Self::Output {
+                    #name(&self.0 #op_symbol rhs.0)
+                }
+            }
+
+            impl<'a, 'b> ::core::ops::#trait_name<&'b #name> for &'a #name
+            where
+                &'a #inner: ::core::ops::#trait_name<&'b #inner, Output = #inner>,
+            {
+                type Output = #name;
+                #function_attributes
+                fn #method(self, rhs: &'b #name) -> Self::Output {
+                    #name(&self.0 #op_symbol &rhs.0)
+                }
+            }
+
+            impl<'a> ::core::ops::#assign_trait<&'a Self> for #name
+            where
+                #inner: ::core::ops::#assign_trait<&'a #inner>,
+            {
+                #function_attributes
+                fn #assign_method(&mut self, rhs: &'a Self) {
+                    self.0 #assign_op_symbol &rhs.0
+                }
+            }
+        });
+    }
+
+    if let Some(iterator_trait_name) = spec.iterator_trait {
+        let inline = attributes.inline;
+        let iterator_trait = format_ident!("{}", iterator_trait_name);
+        let iterator_method = format_ident!("{}", iterator_trait_name.to_lowercase());
+        result.extend(quote! {
+            impl ::core::iter::#iterator_trait<Self> for #name
+            where
+                for<'a> #inner: ::core::iter::#iterator_trait<#inner>,
+            {
+                #inline
+                fn #iterator_method<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                    Self(::core::iter::Iterator::#iterator_method(::core::iter::Iterator::map(iter, |item| item.0)))
+                }
+            }
+
+            impl<'a> ::core::iter::#iterator_trait<&'a Self> for #name
+            where
+                #inner: ::core::iter::#iterator_trait<&'a #inner>,
+            {
+                #inline
+                fn #iterator_method<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    Self(::core::iter::Iterator::#iterator_method(::core::iter::Iterator::map(iter, |item| &item.0)))
+                }
+            }
+        });
+    }
+
+    result
+}
+
+fn unary(
+    name: &syn::Ident,
+    inner: &syn::Type,
+    spec: &UnaryOperatorSpec,
+    mode: GenerationMode,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
+
+    let mut result = quote! {
+        impl ::core::ops::#trait_name for #name
+        where
+            for<'a> #inner: ::core::ops::#trait_name<Output = #inner>,
+        {
+            type Output = Self;
+            #function_attributes
+            fn #method(self) -> Self::Output {
+                Self(#op_symbol self.0)
+            }
+        }
+    };
+
+    if mode == GenerationMode::Full {
+        result.extend(quote! {
+            impl<'a> ::core::ops::#trait_name for &'a #name
+            where
+                &'a #inner: ::core::ops::#trait_name<Output = #inner>,
+            {
+                type Output = #name;
+                #function_attributes
+                fn #method(self) -> Self::Output {
+                    #name(#op_symbol &self.0)
+                }
+            }
+        });
+    }
+
+    result
+}
+
+/// An operator with a right-hand side that is not a strong type: a shift amount or a scalar
+fn shift(
+    name: &syn::Ident,
+    inner: &syn::Type,
+    spec: &BinaryOperatorSpec,
+    rhs: &syn::Type,
+    mode: GenerationMode,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let assign_trait = format_ident!("{}", spec.assign_trait);
+    let assign_method = format_ident!("{}", spec.assign_method);
+    let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
+    let assign_op_symbol = syn::parse_str::<TokenStream>(spec.assign_op_symbol).unwrap();
+
+    let mut result = quote! {
+        impl ::core::ops::#trait_name<#rhs> for #name
+        where
+            for<'a> #inner: ::core::ops::#trait_name<#rhs, Output = #inner>,
+        {
+            type Output = Self;
+            #function_attributes
+            fn #method(self, rhs: #rhs) -> Self::Output {
+                Self(self.0 #op_symbol rhs)
+            }
+        }
+
+        impl ::core::ops::#assign_trait<#rhs> for #name
+        where
+            for<'a> #inner: ::core::ops::#assign_trait<#rhs>,
+        {
+            #function_attributes
+            fn #assign_method(&mut self, rhs: #rhs) {
+                self.0 #assign_op_symbol rhs;
+            }
+        }
+    };
+
+    if mode == GenerationMode::Full {
+        result.extend(quote! {
+            impl<'a> ::core::ops::#trait_name<#rhs> for &'a #name
+            where
+                &'a #inner: ::core::ops::#trait_name<#rhs, Output = #inner>,
+            {
+                type Output = #name;
+                #function_attributes
+                fn #method(self, rhs: #rhs) -> Self::Output {
+                    #name(&self.0 #op_symbol rhs)
+                }
+            }
+        });
+    }
+
+    result
+}
+
+/// A scalar operator, with the scalar on the left as well if the operator is commutative
+fn scalar(
+    name: &syn::Ident,
+    inner: &syn::Type,
+    primitive: &syn::Type,
+    spec: &ScalarOperatorSpec,
+    mode: GenerationMode,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let function_attributes = attributes.function(spec.may_panic);
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
+
+    let mut result = shift(
+        name,
+        inner,
+        &BinaryOperatorSpec {
+            trait_name: spec.trait_name,
+            method: spec.method,
+            op_symbol: spec.op_symbol,
+            assign_trait: spec.assign_trait,
+            assign_method: spec.assign_method,
+            assign_op_symbol: spec.assign_op_symbol,
+            iterator_trait: None,
+            may_panic: spec.may_panic,
+        },
+        primitive,
+        mode,
+        attributes,
+    );
+
+    if spec.commutative {
+        result.extend(quote! {
+            impl ::core::ops::#trait_name<#name> for #primitive
+            where
+                for<'a> #primitive: ::core::ops::#trait_name<#inner, Output = #inner>,
+            {
+                type Output = #name;
+                #function_attributes
+                fn #method(self, rhs: #name) -> Self::Output {
+                    #name(self #op_symbol rhs.0)
+                }
+            }
+        });
+
+        if mode == GenerationMode::Full {
+            result.extend(quote! {
+                impl<'a> ::core::ops::#trait_name<&'a #name> for #primitive
+                where
+                    #primitive: ::core::ops::#trait_name<&'a #inner, Output = #inner>,
+                {
+                    type Output = #name;
+                    #function_attributes
+                    fn #method(self, rhs: &'a #name) -> Self::Output {
+                        #name(self #op_symbol &rhs.0)
+                    }
+                }
+            });
+        }
+    }
+
+    result
+}
+
+/// The affine operators of a point type: `Point - Point = Delta`, `Point ± Delta = Point` and
+/// `Delta + Point = Point`, computed on the inner value and the delta itself, so that the inner
+/// type may be a point type with the same delta or a numeric type that is its own delta
+fn point(
+    name: &syn::Ident,
+    inner: &syn::Type,
+    delta: &syn::Ident,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let delta: syn::Type = syn::parse_quote!(#delta);
+    let mut result = TokenStream::new();
+    for (operands, spec) in [
+        (POINT_POINT_TO_DELTA, &binary_ops::SUB),
+        (POINT_DELTA_TO_POINT, &binary_ops::ADD),
+        (POINT_DELTA_TO_POINT, &binary_ops::SUB),
+        (DELTA_POINT_TO_POINT, &binary_ops::ADD),
+    ] {
+        result.extend(mixed(name, inner, &delta, operands, spec, attributes));
+    }
+    result
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Point,
+    Delta,
+}
+
+/// The left operand, right operand and output of a point operator
+type Operands = (Operand, Operand, Operand);
+
+const POINT_POINT_TO_DELTA: Operands = (Operand::Point, Operand::Point, Operand::Delta);
+const POINT_DELTA_TO_POINT: Operands = (Operand::Point, Operand::Delta, Operand::Point);
+const DELTA_POINT_TO_POINT: Operands = (Operand::Delta, Operand::Point, Operand::Point);
+
+/// One point operator in all ownership variants. Borrowed operands are cloned, since the
+/// operators of the inner type are only required for owned values.
+fn mixed(
+    name: &syn::Ident,
+    inner: &syn::Type,
+    delta: &syn::Type,
+    (lhs, rhs, output): Operands,
+    spec: &BinaryOperatorSpec,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let function_attributes = attributes.function(true);
+    let trait_name = format_ident!("{}", spec.trait_name);
+    let method = format_ident!("{}", spec.method);
+    let assign_trait = format_ident!("{}", spec.assign_trait);
+    let assign_method = format_ident!("{}", spec.assign_method);
+    let op_symbol = syn::parse_str::<TokenStream>(spec.op_symbol).unwrap();
+    let assign_op_symbol = syn::parse_str::<TokenStream>(spec.assign_op_symbol).unwrap();
+
+    let outer = |operand| match operand {
+        Operand::Point => quote!(#name),
+        Operand::Delta => quote!(#delta),
+    };
+    let value = |operand| match operand {
+        Operand::Point => quote!(#inner),
+        Operand::Delta => quote!(#delta),
+    };
+    let (lhs_type, rhs_type, output_type) = (outer(lhs), outer(rhs), outer(output));
+    let (lhs_value, rhs_value, output_value) = (value(lhs), value(rhs), value(output));
+    let operand = |operand, ident: TokenStream, borrowed: bool| match (operand, borrowed) {
+        (Operand::Point, false) => quote!(#ident.0),
+        (Operand::Point, true) => quote!(::core::clone::Clone::clone(&#ident.0)),
+        (Operand::Delta, false) => ident,
+        (Operand::Delta, true) => quote!(::core::clone::Clone::clone(#ident)),
+    };
+    let body = |lhs_borrowed, rhs_borrowed| {
+        let lhs = operand(lhs, quote!(self), lhs_borrowed);
+        let rhs = operand(rhs, quote!(rhs), rhs_borrowed);
+        match output {
+            Operand::Point => quote!(#name(#lhs #op_symbol #rhs)),
+            Operand::Delta => quote!(#lhs #op_symbol #rhs),
+        }
+    };
+    let (owned, rhs_borrowed, lhs_borrowed, both_borrowed) = (
+        body(false, false),
+        body(false, true),
+        body(true, false),
+        body(true, true),
+    );
+
+    let mut result = quote! {
+        impl ::core::ops::#trait_name<#rhs_type> for #lhs_type
+        where
+            for<'x> #lhs_value: ::core::ops::#trait_name<#rhs_value, Output = #output_value>,
+        {
+            type Output = #output_type;
+            #function_attributes
+            fn #method(self, rhs: #rhs_type) -> Self::Output {
+                #owned
+            }
+        }
+
+        impl<'a> ::core::ops::#trait_name<&'a #rhs_type> for #lhs_type
+        where
+            for<'x> #lhs_value: ::core::ops::#trait_name<#rhs_value, Output = #output_value>,
+            for<'x> #rhs_value: ::core::clone::Clone,
+        {
+            type Output = #output_type;
+            #function_attributes
+            fn #method(self, rhs: &'a #rhs_type) -> Self::Output {
+                #rhs_borrowed
+            }
+        }
+
+        impl<'a> ::core::ops::#trait_name<#rhs_type> for &'a #lhs_type
+        where
+            for<'x> #lhs_value:
+                ::core::ops::#trait_name<#rhs_value, Output = #output_value> + ::core::clone::Clone,
+        {
+            type Output = #output_type;
+            #function_attributes
+            fn #method(self, rhs: #rhs_type) -> Self::Output {
+                #lhs_borrowed
+            }
+        }
+
+        impl<'a, 'b> ::core::ops::#trait_name<&'b #rhs_type> for &'a #lhs_type
+        where
+            for<'x> #lhs_value:
+                ::core::ops::#trait_name<#rhs_value, Output = #output_value> + ::core::clone::Clone,
+            for<'x> #rhs_value: ::core::clone::Clone,
+        {
+            type Output = #output_type;
+            #function_attributes
+            fn #method(self, rhs: &'b #rhs_type) -> Self::Output {
+                #both_borrowed
+            }
+        }
+    };
+
+    if lhs == output {
+        let (owned, borrowed) = (
+            operand(rhs, quote!(rhs), false),
+            operand(rhs, quote!(rhs), true),
+        );
+        result.extend(quote! {
+            impl ::core::ops::#assign_trait<#rhs_type> for #lhs_type
+            where
+                for<'x> #lhs_value: ::core::ops::#assign_trait<#rhs_value>,
+            {
+                #function_attributes
+                fn #assign_method(&mut self, rhs: #rhs_type) {
+                    self.0 #assign_op_symbol #owned
+                }
+            }
+
+            impl<'a> ::core::ops::#assign_trait<&'a #rhs_type> for #lhs_type
+            where
+                for<'x> #lhs_value: ::core::ops::#assign_trait<#rhs_value>,
+                for<'x> #rhs_value: ::core::clone::Clone,
+            {
+                #function_attributes
+                fn #assign_method(&mut self, rhs: &'a #rhs_type) {
+                    self.0 #assign_op_symbol #borrowed
+                }
+            }
+        });
+    }
+
+    result
+}
@@ -0,0 +1,45 @@
+use crate::detail::{InlineMode, Normalization};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `normalize` function applied by every constructor of a normalized string type,
+/// and `FromStr`, which cannot fail since any string can be normalized. Constrained types generate
+/// a fallible `FromStr` instead.
+pub(crate) fn implement_normalize(
+    name: &syn::Ident,
+    normalization: &Normalization,
+    with_from_str: bool,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    let body = match normalization {
+        Normalization::Lowercase => quote! { value.to_lowercase() },
+        Normalization::Trim => quote! { #crate_path::__private::ToOwned::to_owned(value.trim()) },
+        Normalization::Function(function) => quote! { #function(value) },
+    };
+
+    let mut result = quote! {
+        impl #name {
+            /// Normalizes a string the way every constructor of this type does
+            #inline
+            pub fn normalize(value: &str) -> #crate_path::__private::String {
+                #body
+            }
+        }
+    };
+
+    if with_from_str {
+        result.extend(quote! {
+            impl ::core::str::FromStr for #name {
+                type Err = ::core::convert::Infallible;
+
+                #inline
+                fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                    ::core::result::Result::Ok(Self(::core::convert::Into::into(Self::normalize(value))))
+                }
+            }
+        });
+    }
+
+    result
+}
@@ -0,0 +1,34 @@
+//! Strong types wrapping arbitrary types with `#[strong_type(opaque)]`, such as `Uuid`,
+//! `IpAddr` or `PathBuf`.
+//!
+//! Nothing is known about the wrapped type beyond what `StrongType` requires, so the value is
+//! only exposed by reference, and the other traits are bounded on the wrapped type like for
+//! nested strong types.
+
+use crate::detail::{InlineMode, SkippableTrait, implement_bounded_traits};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+
+pub(crate) fn implement_opaque(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    skipped: &BTreeSet<SkippableTrait>,
+    inline: InlineMode,
+) -> TokenStream {
+    let mut result = quote! {
+        impl #name {
+            #inline
+            pub fn value(&self) -> &#value_type {
+                &self.0
+            }
+
+            #inline
+            pub fn primitive(&self) -> &#value_type {
+                &self.0
+            }
+        }
+    };
+    result.extend(implement_bounded_traits(name, value_type, skipped, inline));
+    result
+}
@@ -0,0 +1,381 @@
+//! Operator selection and generation.
+//!
+//! The operators of a strong type are resolved once into an [`OperatorSet`] from the
+//! `auto_operators`, `addable`, `scalable` and `ops(...)` attributes. Each selected operator is
+//! then generated from the spec tables in `codegen_framework`, using the ownership variants and
+//! body strategy of the `auto_operators` mode:
+//!
+//! - Full mode generates all ownership variants (`T op T`, `T op &T`, `&T op T`, `&T op &T`),
+//!   assignment operators and `Sum`/`Product` over owned and borrowed items.
+//! - Minimal mode only generates owned operands, assignment operators and iterator traits,
+//!   reducing binary size by ~75% compared to full mode.
+//! - Delegated mode generates all ownership variants, but forwards owned bodies and
+//!   `Sum`/`Product` folds to shared generic functions in `strong_type::delegation` (requires
+//!   the `StrongTypeOps` impl), reducing binary size by 30-50% in debug builds at the cost of
+//!   `#[inline(never)]` calls.
+//! - Minimal delegated mode combines the two: owned operands only, with delegated bodies and
+//!   iterator folds.
+
+use super::codegen_framework::{
+    BinaryOperatorSpec, DelegationStrategy, GenerationMode, UnaryOperatorSpec, binary_ops,
+    generate_binary_operator_with_strategy, generate_shift_operator,
+    generate_unary_operator_with_strategy, shift_ops, unary_ops,
+};
+use crate::detail::{AutoOperatorMode, OperatorAttributes, ValueTypeGroup};
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use std::collections::BTreeSet;
+
+/// An individually selectable operator, as written in `#[strong_type(ops(...))]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Neg,
+    Not,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+}
+
+impl Operator {
+    pub(crate) const ALL: [Operator; 12] = [
+        Operator::Add,
+        Operator::Sub,
+        Operator::Mul,
+        Operator::Div,
+        Operator::Rem,
+        Operator::Neg,
+        Operator::Not,
+        Operator::BitAnd,
+        Operator::BitOr,
+        Operator::BitXor,
+        Operator::Shl,
+        Operator::Shr,
+    ];
+
+    /// The keyword used for this operator in `ops(...)`
+    pub(crate) fn keyword(self) -> &'static str {
+        match self {
+            Operator::Add => "add",
+            Operator::Sub => "sub",
+            Operator::Mul => "mul",
+            Operator::Div => "div",
+            Operator::Rem => "rem",
+            Operator::Neg => "neg",
+            Operator::Not => "not",
+            Operator::BitAnd => "bitand",
+            Operator::BitOr => "bitor",
+            Operator::BitXor => "bitxor",
+            Operator::Shl => "shl",
+            Operator::Shr => "shr",
+        }
+    }
+
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|operator| operator.keyword() == keyword)
+    }
+
+    /// Comma-separated list of all keywords, for error messages
+    pub(crate) fn keywords() -> String {
+        Self::ALL.map(Operator::keyword).join(", ")
+    }
+
+    /// Whether the operator is meaningful for the primitive of the given type group
+    pub(crate) fn supports(self, type_group: &ValueTypeGroup) -> bool {
+        match type_group {
+            // Nested types forward to the inner type, whose operators are checked on use
+            ValueTypeGroup::Int(_) | ValueTypeGroup::Nested => true,
+            ValueTypeGroup::UInt(_) => self != Operator::Neg,
+            ValueTypeGroup::Float(_) => matches!(
+                self,
+                Operator::Add
+                    | Operator::Sub
+                    | Operator::Mul
+                    | Operator::Div
+                    | Operator::Rem
+                    | Operator::Neg
+            ),
+            ValueTypeGroup::Bool(_) => matches!(
+                self,
+                Operator::Not | Operator::BitAnd | Operator::BitOr | Operator::BitXor
+            ),
+            ValueTypeGroup::Char(_)
+            | ValueTypeGroup::String(_)
+            | ValueTypeGroup::CowStr
+            | ValueTypeGroup::Opaque => false,
+        }
+    }
+}
+
+/// The set of operators to generate for a strong type
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OperatorSet(BTreeSet<Operator>);
+
+impl OperatorSet {
+    /// The operators generated by `auto_operators` for a type group
+    pub(crate) fn auto(type_group: &ValueTypeGroup, mode: AutoOperatorMode) -> Self {
+        let operators: &[Operator] = match (type_group, mode) {
+            (_, AutoOperatorMode::None) => &[],
+            (
+                ValueTypeGroup::Int(_) | ValueTypeGroup::UInt(_) | ValueTypeGroup::Nested,
+                AutoOperatorMode::Minimal | AutoOperatorMode::MinimalDelegated,
+            ) => &[
+                Operator::Add,
+                Operator::Sub,
+                Operator::Mul,
+                Operator::Div,
+                Operator::Rem,
+                Operator::Neg,
+            ],
+            // Nested types get the integer operators, which are only required of the inner
+            // type once used
+            (ValueTypeGroup::Int(_) | ValueTypeGroup::UInt(_) | ValueTypeGroup::Nested, _) => &[
+                Operator::Add,
+                Operator::Sub,
+                Operator::Mul,
+                Operator::Div,
+                Operator::Rem,
+                Operator::Neg,
+                Operator::BitAnd,
+                Operator::BitOr,
+                Operator::BitXor,
+                Operator::Shl,
+                Operator::Shr,
+            ],
+            (ValueTypeGroup::Float(_), _) => &[
+                Operator::Add,
+                Operator::Sub,
+                Operator::Mul,
+                Operator::Div,
+                Operator::Rem,
+                Operator::Neg,
+            ],
+            (ValueTypeGroup::Bool(_), _) => &[
+                Operator::Not,
+                Operator::BitAnd,
+                Operator::BitOr,
+                Operator::BitXor,
+            ],
+            (
+                ValueTypeGroup::Char(_)
+                | ValueTypeGroup::String(_)
+                | ValueTypeGroup::CowStr
+                | ValueTypeGroup::Opaque,
+                _,
+            ) => &[],
+        };
+
+        let mut result = Self::default();
+        result.extend_supported(type_group, operators);
+        result
+    }
+
+    pub(crate) fn insert(&mut self, operator: Operator) {
+        self.0.insert(operator);
+    }
+
+    /// Adds the operators that apply to the type group, skipping the others
+    pub(crate) fn extend_supported(&mut self, type_group: &ValueTypeGroup, operators: &[Operator]) {
+        self.0.extend(
+            operators
+                .iter()
+                .copied()
+                .filter(|operator| operator.supports(type_group)),
+        );
+    }
+
+    pub(crate) fn contains(&self, operator: Operator) -> bool {
+        self.0.contains(&operator)
+    }
+}
+
+/// Maps an `auto_operators` mode to the ownership variants and body strategy it generates
+pub(crate) fn generation_settings(mode: AutoOperatorMode) -> (GenerationMode, DelegationStrategy) {
+    match mode {
+        AutoOperatorMode::None | AutoOperatorMode::Full => {
+            (GenerationMode::Full, DelegationStrategy::Inline)
+        }
+        AutoOperatorMode::Minimal => (GenerationMode::Minimal, DelegationStrategy::Inline),
+        AutoOperatorMode::Delegated => (GenerationMode::Full, DelegationStrategy::Delegate),
+        AutoOperatorMode::MinimalDelegated => {
+            (GenerationMode::Minimal, DelegationStrategy::Delegate)
+        }
+    }
+}
+
+/// Generates every operator in the set
+pub(crate) fn implement_operators(
+    name: &syn::Ident,
+    operators: &OperatorSet,
+    shift_rhs: &[syn::Ident],
+    mode: AutoOperatorMode,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let (mode, strategy) = generation_settings(mode);
+
+    let mut result = TokenStream::new();
+    for operator in operators.0.iter().copied() {
+        result.extend(match operator {
+            Operator::Neg => unary(
+                name,
+                &unary_ops::NEG,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::Not => unary(
+                name,
+                &unary_ops::NOT,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::Add => binary(
+                name,
+                &binary_ops::ADD,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::Sub => binary(
+                name,
+                &binary_ops::SUB,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::Mul => binary(
+                name,
+                &binary_ops::MUL,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::Div => binary(
+                name,
+                &binary_ops::DIV,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::Rem => binary(
+                name,
+                &binary_ops::REM,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::BitAnd => binary(
+                name,
+                &binary_ops::BITAND,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::BitOr => binary(
+                name,
+                &binary_ops::BITOR,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::BitXor => binary(
+                name,
+                &binary_ops::BITXOR,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::Shl => shift(
+                name,
+                &shift_ops::SHL,
+                shift_rhs,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+            Operator::Shr => shift(
+                name,
+                &shift_ops::SHR,
+                shift_rhs,
+                mode,
+                strategy,
+                crate_path,
+                attributes,
+            ),
+        });
+    }
+    result
+}
+
+fn unary(
+    name: &syn::Ident,
+    spec: &UnaryOperatorSpec,
+    mode: GenerationMode,
+    strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    generate_unary_operator_with_strategy(name, spec, mode, strategy, crate_path, attributes)
+}
+
+fn binary(
+    name: &syn::Ident,
+    spec: &BinaryOperatorSpec,
+    mode: GenerationMode,
+    strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    generate_binary_operator_with_strategy(name, spec, mode, strategy, crate_path, attributes)
+}
+
+/// The right-hand side types of shift operators: the `shift_rhs` type if given, otherwise every
+/// primitive integer type
+pub(crate) fn shift_types(shift_rhs: Option<&syn::Ident>) -> Vec<syn::Ident> {
+    match shift_rhs {
+        Some(shift_rhs) => vec![shift_rhs.clone()],
+        None => shift_ops::SHIFT_TYPES
+            .iter()
+            .map(|type_str| format_ident!("{}", type_str))
+            .collect(),
+    }
+}
+
+fn shift(
+    name: &syn::Ident,
+    spec: &BinaryOperatorSpec,
+    shift_rhs: &[syn::Ident],
+    mode: GenerationMode,
+    strategy: DelegationStrategy,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let mut result = TokenStream::new();
+    for shift_type in shift_rhs {
+        result.extend(generate_shift_operator(
+            name, spec, shift_type, mode, strategy, crate_path, attributes,
+        ));
+    }
+    result
+}
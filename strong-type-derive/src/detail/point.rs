@@ -0,0 +1,11 @@
+use super::codegen_framework::generate_point_operators;
+use crate::detail::OperatorAttributes;
+use proc_macro2::TokenStream;
+
+pub(crate) fn implement_point(
+    name: &syn::Ident,
+    delta: &syn::Ident,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    generate_point_operators(name, delta, attributes)
+}
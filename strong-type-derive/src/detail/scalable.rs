@@ -1,6 +1,14 @@
 use super::codegen_framework::generate_scalable_operators;
+use crate::detail::{AutoOperatorMode, OperatorAttributes, generation_settings};
 use proc_macro2::TokenStream;
 
-pub(crate) fn implement_scalable(name: &syn::Ident, value_type: &syn::Ident) -> TokenStream {
-    generate_scalable_operators(name, value_type)
+pub(crate) fn implement_scalable(
+    name: &syn::Ident,
+    value_type: &syn::Type,
+    auto_operator_mode: AutoOperatorMode,
+    crate_path: &syn::Path,
+    attributes: OperatorAttributes,
+) -> TokenStream {
+    let (_, strategy) = generation_settings(auto_operator_mode);
+    generate_scalable_operators(name, value_type, strategy, crate_path, attributes)
 }
@@ -0,0 +1,79 @@
+//! Opting out of individual generated trait impls with `#[strong_type(skip(...))]`.
+//!
+//! Generators check the skip set before emitting each skippable impl, so the user can provide
+//! their own. `custom_display` is shorthand for `skip(Display)`.
+
+use proc_macro2::TokenStream;
+use std::collections::BTreeSet;
+
+/// A generated trait impl that can be skipped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum SkippableTrait {
+    Debug,
+    Default,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    Hash,
+    AsRef,
+    AsMut,
+    Display,
+}
+
+impl SkippableTrait {
+    pub(crate) const ALL: [SkippableTrait; 10] = [
+        SkippableTrait::Debug,
+        SkippableTrait::Default,
+        SkippableTrait::PartialEq,
+        SkippableTrait::PartialOrd,
+        SkippableTrait::Eq,
+        SkippableTrait::Ord,
+        SkippableTrait::Hash,
+        SkippableTrait::AsRef,
+        SkippableTrait::AsMut,
+        SkippableTrait::Display,
+    ];
+
+    /// The trait name, as written in `skip(...)`
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            SkippableTrait::Debug => "Debug",
+            SkippableTrait::Default => "Default",
+            SkippableTrait::PartialEq => "PartialEq",
+            SkippableTrait::PartialOrd => "PartialOrd",
+            SkippableTrait::Eq => "Eq",
+            SkippableTrait::Ord => "Ord",
+            SkippableTrait::Hash => "Hash",
+            SkippableTrait::AsRef => "AsRef",
+            SkippableTrait::AsMut => "AsMut",
+            SkippableTrait::Display => "Display",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|skippable| skippable.name() == name)
+    }
+
+    /// Comma-separated list of all trait names, for error messages
+    pub(crate) fn names() -> String {
+        Self::ALL.map(SkippableTrait::name).join(", ")
+    }
+}
+
+/// Returns the impl of a skippable trait, or nothing if the user skipped it. Only impls of the
+/// trait for the strong type itself go through here; impls relating it to other types, such as
+/// `PartialEq<Borrowed>`, are always generated.
+pub(crate) fn unless_skipped(
+    skipped: &BTreeSet<SkippableTrait>,
+    skippable: SkippableTrait,
+    tokens: TokenStream,
+) -> TokenStream {
+    if skipped.contains(&skippable) {
+        TokenStream::new()
+    } else {
+        tokens
+    }
+}
@@ -0,0 +1,75 @@
+use crate::detail::InlineMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `FromIterator` and `Extend` for string strong types, so that building a value from
+/// characters or segments does not require collecting into a plain `String` first.
+///
+/// `buffer` is an expression yielding the `&mut String` to extend.
+pub(crate) fn implement_string_collection(
+    name: &syn::Ident,
+    buffer: TokenStream,
+    crate_path: &syn::Path,
+    inline: InlineMode,
+) -> TokenStream {
+    quote! {
+        impl ::core::iter::FromIterator<char> for #name {
+            #inline
+            fn from_iter<I: ::core::iter::IntoIterator<Item = char>>(iter: I) -> Self {
+                Self::new(::core::iter::Iterator::collect::<#crate_path::__private::String>(
+                    ::core::iter::IntoIterator::into_iter(iter),
+                ))
+            }
+        }
+
+        impl<'a> ::core::iter::FromIterator<&'a str> for #name {
+            #inline
+            fn from_iter<I: ::core::iter::IntoIterator<Item = &'a str>>(iter: I) -> Self {
+                Self::new(::core::iter::Iterator::collect::<#crate_path::__private::String>(
+                    ::core::iter::IntoIterator::into_iter(iter),
+                ))
+            }
+        }
+
+        impl ::core::iter::FromIterator<#crate_path::__private::String> for #name {
+            #inline
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #crate_path::__private::String>>(iter: I) -> Self {
+                Self::new(::core::iter::Iterator::collect::<#crate_path::__private::String>(
+                    ::core::iter::IntoIterator::into_iter(iter),
+                ))
+            }
+        }
+
+        impl ::core::iter::FromIterator<#name> for #name {
+            #inline
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #name>>(iter: I) -> Self {
+                let mut result = #crate_path::__private::String::new();
+                for item in iter {
+                    result.push_str(item.value());
+                }
+                Self::new(result)
+            }
+        }
+
+        impl ::core::iter::Extend<char> for #name {
+            #inline
+            fn extend<I: ::core::iter::IntoIterator<Item = char>>(&mut self, iter: I) {
+                ::core::iter::Extend::extend(#buffer, iter);
+            }
+        }
+
+        impl<'a> ::core::iter::Extend<&'a str> for #name {
+            #inline
+            fn extend<I: ::core::iter::IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+                ::core::iter::Extend::extend(#buffer, iter);
+            }
+        }
+
+        impl ::core::iter::Extend<#crate_path::__private::String> for #name {
+            #inline
+            fn extend<I: ::core::iter::IntoIterator<Item = #crate_path::__private::String>>(&mut self, iter: I) {
+                ::core::iter::Extend::extend(#buffer, iter);
+            }
+        }
+    }
+}
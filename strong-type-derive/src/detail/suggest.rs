@@ -0,0 +1,38 @@
+//! "Did you mean" suggestions for misspelled attribute names and values.
+
+/// Returns the candidate closest to `name`, if any is close enough to be a likely misspelling
+pub(crate) fn closest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    // Allow roughly one edit per three characters, so short names only match close typos
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Formats a suggestion to append to an error message, or nothing without one
+pub(crate) fn did_you_mean(suggestion: Option<impl AsRef<str>>) -> String {
+    suggestion.map_or_else(String::new, |suggestion| {
+        format!(" Did you mean `{}`?", suggestion.as_ref())
+    })
+}
+
+/// The Levenshtein distance between two strings, in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
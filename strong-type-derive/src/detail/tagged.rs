@@ -0,0 +1,148 @@
+use crate::detail::{
+    InlineMode, SkippableTrait, TaggedDisplay, TaggedMetadata, implement_hash, unless_skipped,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the strong type surface of a fieldless enum: accessors for the discriminant, the
+/// comparison and hashing traits over it, and conversions from the primitive and the variant name.
+pub(crate) fn implement_tagged(metadata: &TaggedMetadata) -> TokenStream {
+    let name = &metadata.name;
+    let primitive_type = &metadata.primitive_type;
+    let crate_path = &metadata.crate_path;
+    let variants = &metadata.variants;
+    let variant_names = variants
+        .iter()
+        .map(|variant| variant.to_string())
+        .collect::<Vec<_>>();
+
+    let mut result = quote! {
+        impl #name {
+            /// All variants, in declaration order
+            pub const VARIANTS: &'static [Self] = &[#(Self::#variants),*];
+
+            pub const fn value(&self) -> #primitive_type {
+                *self as #primitive_type
+            }
+
+            pub const fn primitive(&self) -> #primitive_type {
+                self.value()
+            }
+
+            /// The name of the variant
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #variant_names,)*
+                }
+            }
+        }
+
+        impl #crate_path::StrongType for #name {
+            type UnderlyingType = #primitive_type;
+            type PrimitiveType = #primitive_type;
+        }
+
+        impl #crate_path::StrongTypeAccess for #name {
+            type Value<'a> = Self;
+            type Primitive<'a> = #primitive_type;
+
+            fn as_value(&self) -> Self::Value<'_> {
+                *self
+            }
+
+            fn as_primitive(&self) -> Self::Primitive<'_> {
+                #name::primitive(self)
+            }
+        }
+
+        impl ::core::marker::Copy for #name {}
+
+        impl ::core::clone::Clone for #name {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl ::core::convert::From<#name> for #primitive_type {
+            fn from(value: #name) -> Self {
+                value.value()
+            }
+        }
+
+        impl ::core::convert::TryFrom<#primitive_type> for #name {
+            type Error = #crate_path::Error;
+
+            fn try_from(value: #primitive_type) -> ::core::result::Result<Self, Self::Error> {
+                ::core::iter::Iterator::find(&mut Self::VARIANTS.iter(), |variant| variant.value() == value)
+                    .copied()
+                    .ok_or_else(|| #crate_path::Error::unknown_variant(::core::stringify!(#name), value))
+            }
+        }
+
+        impl ::core::str::FromStr for #name {
+            type Err = #crate_path::Error;
+
+            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                match value {
+                    #(#variant_names => ::core::result::Result::Ok(Self::#variants),)*
+                    _ => ::core::result::Result::Err(#crate_path::Error::unknown_variant(::core::stringify!(#name), value)),
+                }
+            }
+        }
+    };
+
+    let skipped = &metadata.skipped_traits;
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Debug,
+        quote! {
+            impl ::core::fmt::Debug for #name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::PartialEq,
+        quote! {
+            impl ::core::cmp::PartialEq for #name {
+                fn eq(&self, rhs: &Self) -> bool {
+                    self.value() == rhs.value()
+                }
+            }
+        },
+    ));
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::PartialOrd,
+        quote! {
+            #[allow(clippy::incorrect_partial_ord_impl_on_ord_type)]
+            impl ::core::cmp::PartialOrd for #name {
+                fn partial_cmp(&self, rhs: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                     ::core::cmp::PartialOrd::partial_cmp(&self.value(), &rhs.value())
+                }
+            }
+        },
+    ));
+    result.extend(implement_hash(name, skipped, InlineMode::Default));
+
+    let display = match metadata.display {
+        TaggedDisplay::Name => quote! { f.write_str(self.name()) },
+        TaggedDisplay::Value => quote! { ::core::write!(f, "{}", self.value()) },
+    };
+    result.extend(unless_skipped(
+        skipped,
+        SkippableTrait::Display,
+        quote! {
+            impl ::core::fmt::Display for #name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    #display
+                }
+            }
+        },
+    ));
+
+    result
+}
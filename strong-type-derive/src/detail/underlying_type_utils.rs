@@ -1,4 +1,4 @@
-use syn::{Data, DeriveInput, Type};
+use syn::{Data, DeriveInput, GenericArgument, PathArguments, Type};
 
 pub(crate) enum UnderlyingType {
     Primitive,
@@ -11,11 +11,18 @@ pub(crate) enum ValueTypeGroup {
     Bool(UnderlyingType),
     Char(UnderlyingType),
     String(UnderlyingType),
+    /// `Cow<'static, str>`, which can be built in `const` contexts from a `&'static str`
+    CowStr,
+    /// Another strong type, whose primitive type is resolved through its `StrongType` impl
+    /// because no `underlying` attribute names it
+    Nested,
+    /// Any type, wrapped with `#[strong_type(opaque)]`
+    Opaque,
 }
 
 pub(crate) struct TypeInfo {
-    pub primitive_type: syn::Ident,
-    pub value_type: syn::Ident,
+    pub primitive_type: syn::Type,
+    pub value_type: syn::Type,
     pub type_group: Option<ValueTypeGroup>,
 }
 
@@ -57,21 +64,144 @@ pub(crate) fn get_type_group(
     None
 }
 
-fn get_type_ident(input: &DeriveInput) -> Option<syn::Ident> {
-    if let Data::Struct(ref data_struct) = input.data
-        && let Type::Path(path) = &data_struct.fields.iter().next().unwrap().ty
-    {
-        return Some(path.path.segments.last().unwrap().ident.clone());
+/// The modules through which a supported type may be named, besides being named bare
+const PRIMITIVE_MODULES: &[[&str; 2]] = &[["core", "primitive"], ["std", "primitive"]];
+const STRING_MODULES: &[[&str; 2]] = &[["std", "string"], ["alloc", "string"]];
+const COW_MODULES: &[[&str; 2]] = &[["std", "borrow"], ["alloc", "borrow"]];
+
+/// Returns true for `Cow<'static, str>`.
+fn is_static_cow_str(segment: &syn::PathSegment) -> bool {
+    if segment.ident != "Cow" {
+        return false;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return false;
+    };
+    let mut arguments = arguments.args.iter();
+    matches!(
+        (arguments.next(), arguments.next(), arguments.next()),
+        (
+            Some(GenericArgument::Lifetime(lifetime)),
+            Some(GenericArgument::Type(Type::Path(inner))),
+            None,
+        ) if lifetime.ident == "static" && inner.path.is_ident("str")
+    )
+}
+
+/// Whether the segments before the last one are empty or name one of `modules`, so that a user
+/// type that happens to be called `String` is not mistaken for `std::string::String`
+fn is_in_module(path: &syn::Path, modules: &[[&str; 2]]) -> bool {
+    let prefix = &path.segments.iter().collect::<Vec<_>>()[..path.segments.len() - 1];
+    if prefix.is_empty() {
+        return path.leading_colon.is_none();
+    }
+    modules.iter().any(|module| {
+        prefix.len() == module.len()
+            && prefix.iter().zip(module).all(|(segment, name)| {
+                segment.ident == name && matches!(segment.arguments, PathArguments::None)
+            })
+    })
+}
+
+/// Resolves the type group of a supported type named by `path`, either bare or through its
+/// standard module, such as `core::primitive::i32` or `std::string::String`
+pub(crate) fn get_path_type_group(
+    path: &syn::Path,
+    underlying_type: UnderlyingType,
+) -> Option<ValueTypeGroup> {
+    let segment = path.segments.last()?;
+    if is_static_cow_str(segment) {
+        return is_in_module(path, COW_MODULES).then_some(ValueTypeGroup::CowStr);
+    }
+    if !matches!(segment.arguments, PathArguments::None) {
+        return None;
+    }
+    let type_group = get_type_group(&segment.ident, underlying_type)?;
+    let modules = match type_group {
+        ValueTypeGroup::String(_) => STRING_MODULES,
+        _ => PRIMITIVE_MODULES,
+    };
+    is_in_module(path, modules).then_some(type_group)
+}
+
+/// The borrowed field types supported for strong types with a lifetime parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BorrowedKind {
+    /// `&'a str`
+    Str,
+    /// `&'a [u8]`
+    Bytes,
+    /// `Cow<'a, str>`
+    CowStr,
+}
+
+/// Resolves the kind of a field borrowing for `lifetime`, if it is a supported borrowed type
+pub(crate) fn get_borrowed_kind(
+    value_type: &Type,
+    lifetime: &syn::Lifetime,
+) -> Option<BorrowedKind> {
+    match value_type {
+        Type::Reference(reference)
+            if reference.mutability.is_none() && reference.lifetime.as_ref() == Some(lifetime) =>
+        {
+            match reference.elem.as_ref() {
+                Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => {
+                    Some(BorrowedKind::Str)
+                }
+                Type::Slice(slice) if matches!(slice.elem.as_ref(), Type::Path(path) if path.qself.is_none() && path.path.is_ident("u8")) => {
+                    Some(BorrowedKind::Bytes)
+                }
+                _ => None,
+            }
+        }
+        Type::Path(path) if path.qself.is_none() && is_in_module(&path.path, COW_MODULES) => {
+            let segment = path.path.segments.last()?;
+            let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+                return None;
+            };
+            let mut arguments = arguments.args.iter();
+            matches!(
+                (arguments.next(), arguments.next(), arguments.next()),
+                (
+                    Some(GenericArgument::Lifetime(cow_lifetime)),
+                    Some(GenericArgument::Type(Type::Path(inner))),
+                    None,
+                ) if segment.ident == "Cow" && cow_lifetime == lifetime && inner.path.is_ident("str")
+            )
+            .then_some(BorrowedKind::CowStr)
+        }
+        _ => None,
+    }
+}
+
+/// Resolves the type group of a supported type, see [`get_path_type_group`]
+pub(crate) fn resolve_type_group(value_type: &Type) -> Option<ValueTypeGroup> {
+    match value_type {
+        Type::Path(path) if path.qself.is_none() => {
+            get_path_type_group(&path.path, UnderlyingType::Primitive)
+        }
+        _ => None,
+    }
+}
+
+fn get_value_type(input: &DeriveInput) -> Option<&Type> {
+    if let Data::Struct(ref data_struct) = input.data {
+        return data_struct.fields.iter().next().map(|field| &field.ty);
     }
     None
 }
 
 pub(crate) fn get_type(input: &DeriveInput) -> Result<TypeInfo, syn::Error> {
-    if let Some(value_type) = get_type_ident(input) {
+    if let Some(value_type) = get_value_type(input) {
+        let type_group = resolve_type_group(value_type);
+        // Any other named type is assumed to be a strong type itself, whose primitive type is
+        // projected through `StrongType` once the crate path is known
+        let type_group = type_group
+            .or_else(|| matches!(value_type, Type::Path(_)).then_some(ValueTypeGroup::Nested));
         return Ok(TypeInfo {
             primitive_type: value_type.clone(),
             value_type: value_type.clone(),
-            type_group: get_type_group(&value_type, UnderlyingType::Primitive),
+            type_group,
         });
     }
     Err(syn::Error::new_spanned(
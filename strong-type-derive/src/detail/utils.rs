@@ -1,11 +1,57 @@
-use crate::detail::underlying_type_utils::get_type_group;
-use crate::detail::{TypeInfo, UnderlyingType, ValueTypeGroup, get_type};
+use crate::detail::codegen_framework::shift_ops;
+use crate::detail::underlying_type_utils::{
+    get_path_type_group, get_type_group, resolve_type_group,
+};
+use crate::detail::{
+    Access, BorrowedKind, Charset, Operator, OperatorSet, SkippableTrait, StringConstraints,
+    TypeInfo, UnderlyingType, ValueTypeGroup, closest, default_crate_path, did_you_mean,
+    get_borrowed_kind, get_type, parse_crate_path, shift_types,
+};
 use quote::ToTokens;
-use syn::{Data, DeriveInput, Fields};
+use std::collections::BTreeSet;
+use syn::{Data, DataEnum, DeriveInput, Fields};
 
 const SUPPORTED_PRIMITIVES: &str =
     "i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, String";
 
+/// The attributes of `#[strong_type(...)]` on structs, with the syntax listed in error messages
+const STRUCT_ATTRIBUTES: [(&str, &str); 29] = [
+    ("auto_operators", "auto_operators"),
+    ("ops", "ops(...)"),
+    ("inline", "inline=<mode>"),
+    ("addable", "addable"),
+    ("addable_primitive", "addable_primitive"),
+    ("scalable", "scalable"),
+    ("bitflags", "bitflags"),
+    ("custom_display", "custom_display"),
+    ("skip", "skip(...)"),
+    ("conversion", "conversion"),
+    ("collectable", "collectable"),
+    ("normalize", "normalize=<mode>"),
+    ("min_len", "min_len=<n>"),
+    ("max_len", "max_len=<n>"),
+    ("charset", "charset=\"...\""),
+    ("pattern", "pattern=\"...\""),
+    ("tagged", "tagged"),
+    ("point", "point"),
+    ("delta", "delta=<type>"),
+    ("shift_rhs", "shift_rhs=<type>"),
+    ("borrowed", "borrowed=<name>"),
+    ("underlying", "underlying=<type>"),
+    ("primitive", "primitive=<type>"),
+    ("opaque", "opaque"),
+    ("crate", "crate=\"...\""),
+    ("constructor", "constructor=\"<vis>\""),
+    ("accessor", "accessor=\"<vis>\""),
+    ("immutable", "immutable"),
+    ("default", "default=<expr>"),
+];
+
+/// The attributes of `#[strong_type(...)]` on tagged enums
+const TAGGED_ATTRIBUTES: [&str; 4] = ["tagged", "custom_display", "skip", "crate"];
+
+const AUTO_OPERATOR_MODES: [&str; 4] = ["minimal", "full", "delegated", "minimal_delegated"];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum AutoOperatorMode {
     None,
@@ -14,14 +60,52 @@ pub(crate) enum AutoOperatorMode {
     /// Delegated mode - generates operators that delegate to shared implementations
     /// for reduced binary size (30-50% reduction in debug builds)
     Delegated,
+    /// Minimal ownership variants with delegated bodies, for the smallest binaries
+    MinimalDelegated,
+}
+
+/// Inline hint applied to every generated function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InlineMode {
+    /// Leave inlining decisions to the compiler
+    Default,
+    Always,
+    Never,
+}
+
+/// Normalization applied to string strong types on construction
+pub(crate) enum Normalization {
+    Lowercase,
+    Trim,
+    /// A user function with the signature `fn(&str) -> String`
+    Function(syn::Path),
 }
 
 pub(crate) struct StrongTypeAttributes {
     pub auto_operator_mode: AutoOperatorMode,
+    pub inline_mode: InlineMode,
     pub has_addable: bool,
+    pub has_addable_primitive: bool,
     pub has_scalable: bool,
-    pub has_custom_display: bool,
+    pub skipped_traits: BTreeSet<SkippableTrait>,
     pub has_conversion: bool,
+    pub has_collectable: bool,
+    pub has_point: bool,
+    pub operator_selection: Option<Vec<(Operator, syn::Path)>>,
+    pub bitflags: Option<(syn::Path, Vec<(syn::Ident, syn::Expr)>)>,
+    pub shift_rhs: Option<syn::Ident>,
+    pub normalize: Option<(syn::Path, Normalization)>,
+    pub min_len: Option<syn::LitInt>,
+    pub max_len: Option<syn::LitInt>,
+    pub charset: Option<syn::LitStr>,
+    pub pattern: Option<syn::LitStr>,
+    pub borrowed_type: Option<syn::Ident>,
+    pub delta_type: Option<syn::Ident>,
+    pub crate_path: syn::Path,
+    pub constructor_vis: Option<(syn::LitStr, syn::Visibility)>,
+    pub accessor_vis: Option<(syn::LitStr, syn::Visibility)>,
+    pub immutable: bool,
+    pub default: Option<syn::Expr>,
     pub type_info: TypeInfo,
 }
 
@@ -31,22 +115,50 @@ pub(crate) struct StrongTypeAttributes {
 pub(crate) struct TypeMetadata {
     /// The name of the strong type being generated
     pub name: syn::Ident,
+    /// The visibility of the strong type, reused for generated companion types
+    pub vis: syn::Visibility,
     /// The wrapped value type (e.g., i32, String, CustomType)
-    pub value_type: syn::Ident,
+    pub value_type: syn::Type,
     /// The primitive type (for derived types, this is the underlying primitive)
-    pub primitive_type: syn::Ident,
+    pub primitive_type: syn::Type,
     /// The type category (Int, UInt, Float, Bool, Char, String)
     pub type_group: ValueTypeGroup,
     /// How operators should be generated
     pub auto_operator_mode: AutoOperatorMode,
+    /// The inline hint for generated functions
+    pub inline_mode: InlineMode,
     /// Whether to generate addable operators (Add/Sub)
     pub has_addable: bool,
+    /// The operators to generate, resolved from auto_operators, addable, scalable and ops(...)
+    pub operators: OperatorSet,
+    /// Whether to generate Add/Sub between the type and its primitive
+    pub has_addable_primitive: bool,
     /// Whether to generate scalable operators (scalar multiplication/division)
     pub has_scalable: bool,
-    /// Whether the user provided a custom Display implementation
-    pub has_custom_display: bool,
+    /// The generated trait impls the user opted out of with skip(...) or custom_display
+    pub skipped_traits: BTreeSet<SkippableTrait>,
     /// Whether to generate From/Into conversion traits
     pub has_conversion: bool,
+    /// Whether to generate FromIterator/Extend for string types
+    pub has_collectable: bool,
+    /// The right-hand side types of the generated shift operators
+    pub shift_types: Vec<syn::Ident>,
+    /// For flag-set types, the named flag constants to generate
+    pub bitflags: Option<Vec<(syn::Ident, syn::Expr)>>,
+    /// For string types, the normalization applied by every constructor
+    pub normalize: Option<Normalization>,
+    /// For string types, the constraints checked by every constructor
+    pub constraints: Option<StringConstraints>,
+    /// The name of the unsized borrowed companion type to generate, if any
+    pub borrowed_type: Option<syn::Ident>,
+    /// For point types, the delta type produced by subtracting two points
+    pub delta_type: Option<syn::Ident>,
+    /// The path to the strong_type crate, `::strong_type` unless it is re-exported
+    pub crate_path: syn::Path,
+    /// Visibility and mutability of the generated constructors and accessors
+    pub access: Access,
+    /// The value returned by Default, if given with `default = <expr>`
+    pub default: Option<syn::Expr>,
 }
 
 impl TypeMetadata {
@@ -58,22 +170,431 @@ impl TypeMetadata {
             syn::Error::new_spanned(
                 input,
                 format!(
-                    "Unable to determine the primitive type of '{}'. Supported types are: {}",
-                    attributes.type_info.value_type, SUPPORTED_PRIMITIVES
+                    "Unable to determine the primitive type of '{}'. Supported types are: {}, Cow<'static, str>",
+                    attributes.type_info.value_type.to_token_stream(),
+                    SUPPORTED_PRIMITIVES
                 ),
             )
         })?;
 
+        let is_string = matches!(
+            type_group,
+            ValueTypeGroup::String(UnderlyingType::Primitive) | ValueTypeGroup::CowStr
+        );
+        #[cfg(not(feature = "alloc"))]
+        if is_string {
+            return Err(syn::Error::new_spanned(
+                &attributes.type_info.value_type,
+                "Strong types wrapping String or Cow<'static, str> require the `alloc` feature of strong-type.",
+            ));
+        }
+        if attributes.has_collectable && !is_string {
+            return Err(syn::Error::new_spanned(
+                input,
+                "collectable is only supported for strong types wrapping String or Cow<'static, str>.",
+            ));
+        }
+
+        // Operators are generated from the primitive type, which opaque types do not have. Nested
+        // types forward them to the inner strong type instead.
+        if let ValueTypeGroup::Opaque = type_group
+            && let Some(attribute) = [
+                (
+                    "auto_operators",
+                    attributes.auto_operator_mode != AutoOperatorMode::None,
+                ),
+                ("ops", attributes.operator_selection.is_some()),
+                ("addable", attributes.has_addable),
+                ("addable_primitive", attributes.has_addable_primitive),
+                ("scalable", attributes.has_scalable),
+                ("bitflags", attributes.bitflags.is_some()),
+                ("shift_rhs", attributes.shift_rhs.is_some()),
+                ("point", attributes.has_point),
+            ]
+            .into_iter()
+            .find_map(|(attribute, is_set)| is_set.then_some(attribute))
+        {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "{} is not supported for opaque strong types, which have no primitive type.",
+                    attribute
+                ),
+            ));
+        }
+
+        // Nested strong types are numeric if their inner type is, which the bounds of the
+        // generated operators check on use
+        let is_numeric = matches!(
+            type_group,
+            ValueTypeGroup::Int(_)
+                | ValueTypeGroup::UInt(_)
+                | ValueTypeGroup::Float(_)
+                | ValueTypeGroup::Nested
+        );
+        if let Some(attribute) = [
+            ("addable", attributes.has_addable),
+            ("addable_primitive", attributes.has_addable_primitive),
+            ("scalable", attributes.has_scalable),
+        ]
+        .into_iter()
+        .find_map(|(attribute, is_set)| (is_set && !is_numeric).then_some(attribute))
+        {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!("{} is only supported for numeric strong types.", attribute),
+            ));
+        }
+        if attributes.auto_operator_mode != AutoOperatorMode::None
+            && attributes.operator_selection.is_none()
+        {
+            if matches!(
+                type_group,
+                ValueTypeGroup::Char(_) | ValueTypeGroup::String(_) | ValueTypeGroup::CowStr
+            ) {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    format!(
+                        "auto_operators is not supported for strong types wrapping '{}', which have no operators.",
+                        compact_tokens(&attributes.type_info.primitive_type)
+                    ),
+                ));
+            }
+            if attributes.has_addable {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "addable cannot be combined with auto_operators, which already includes its operators.",
+                ));
+            }
+        }
+
+        if let Some(borrowed_type) = &attributes.borrowed_type
+            && !is_string
+        {
+            return Err(syn::Error::new_spanned(
+                borrowed_type,
+                "borrowed is only supported for strong types wrapping String or Cow<'static, str>.",
+            ));
+        }
+
+        // Borrow requires the borrowed type to compare and hash like the owned type
+        let comparison_traits = [
+            SkippableTrait::PartialEq,
+            SkippableTrait::PartialOrd,
+            SkippableTrait::Eq,
+            SkippableTrait::Ord,
+            SkippableTrait::Hash,
+        ];
+        if let Some(borrowed_type) = &attributes.borrowed_type
+            && let Some(skipped) = comparison_traits
+                .iter()
+                .find(|skippable| attributes.skipped_traits.contains(skippable))
+        {
+            return Err(syn::Error::new_spanned(
+                borrowed_type,
+                format!(
+                    "{} cannot be skipped for types with a borrowed companion type, which must compare and hash the same way.",
+                    skipped.name()
+                ),
+            ));
+        }
+        if let ValueTypeGroup::Float(_) = type_group
+            && let Some(skipped) = [
+                SkippableTrait::Eq,
+                SkippableTrait::Ord,
+                SkippableTrait::Hash,
+            ]
+            .iter()
+            .find(|skippable| attributes.skipped_traits.contains(skippable))
+        {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "{} is not generated for floating-point strong types, so it cannot be skipped.",
+                    skipped.name()
+                ),
+            ));
+        }
+
+        let normalize = match attributes.normalize {
+            Some((path, normalization)) => {
+                if !is_string {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "normalize is only supported for strong types wrapping String or Cow<'static, str>.",
+                    ));
+                }
+                if attributes.borrowed_type.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "normalize cannot be combined with borrowed, since a borrowed value is not guaranteed to be normalized.",
+                    ));
+                }
+                if attributes.has_collectable {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "normalize cannot be combined with collectable, since its Extend impls append without normalizing.",
+                    ));
+                }
+                Some(normalization)
+            }
+            None => None,
+        };
+
+        let constraints = if attributes.min_len.is_some()
+            || attributes.max_len.is_some()
+            || attributes.charset.is_some()
+            || attributes.pattern.is_some()
+        {
+            if !is_string {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "min_len, max_len, charset and pattern are only supported for strong types wrapping String or Cow<'static, str>.",
+                ));
+            }
+            if attributes.borrowed_type.is_some() {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "borrowed cannot be combined with min_len, max_len, charset or pattern, since a borrowed value is not validated.",
+                ));
+            }
+            let min_len = attributes
+                .min_len
+                .map(|min_len| min_len.base10_parse::<usize>())
+                .transpose()?;
+            let max_len = attributes
+                .max_len
+                .as_ref()
+                .map(|max_len| max_len.base10_parse::<usize>())
+                .transpose()?;
+            if let (Some(min_len), Some(max_len)) = (min_len, max_len)
+                && min_len > max_len
+            {
+                return Err(syn::Error::new_spanned(
+                    attributes.max_len,
+                    format!("max_len must not be less than min_len ({}).", min_len),
+                ));
+            }
+            if let Some(pattern) = &attributes.pattern {
+                validate_pattern(pattern)?;
+            }
+            Some(StringConstraints {
+                min_len,
+                max_len,
+                charset: attributes.charset.map(Charset::parse).transpose()?,
+                pattern: attributes.pattern,
+            })
+        } else {
+            None
+        };
+
+        if let Some(literal) = restricted_visibility(&attributes.constructor_vis)
+            && (attributes.has_conversion || attributes.has_collectable)
+        {
+            return Err(syn::Error::new_spanned(
+                literal,
+                "A restricted constructor cannot be combined with conversion or collectable, which construct values publicly.",
+            ));
+        }
+        if let Some(literal) = restricted_visibility(&attributes.constructor_vis)
+            && attributes.default.is_some()
+        {
+            return Err(syn::Error::new_spanned(
+                literal,
+                "A restricted constructor cannot be combined with default, whose Default impl constructs values publicly.",
+            ));
+        }
+        if let Some(literal) = restricted_visibility(&attributes.constructor_vis)
+            && attributes.borrowed_type.is_some()
+        {
+            return Err(syn::Error::new_spanned(
+                literal,
+                "A restricted constructor cannot be combined with borrowed, whose companion type has a public constructor and converts to the strong type.",
+            ));
+        }
+        if let Some(literal) = restricted_visibility(&attributes.constructor_vis)
+            && matches!(
+                attributes.auto_operator_mode,
+                AutoOperatorMode::Delegated | AutoOperatorMode::MinimalDelegated
+            )
+        {
+            return Err(syn::Error::new_spanned(
+                literal,
+                "A restricted constructor cannot be combined with delegated auto_operators, whose StrongTypeOps impl constructs values publicly. Use auto_operators = \"full\" or \"minimal\" instead.",
+            ));
+        }
+        if let Some(literal) = restricted_visibility(&attributes.accessor_vis)
+            && attributes.has_conversion
+        {
+            return Err(syn::Error::new_spanned(
+                literal,
+                "A restricted accessor cannot be combined with conversion, which exposes the value publicly.",
+            ));
+        }
+        if attributes.immutable && attributes.has_collectable {
+            return Err(syn::Error::new_spanned(
+                input,
+                "immutable cannot be combined with collectable, whose Extend impls mutate the value in place.",
+            ));
+        }
+        if constraints.is_some() && attributes.has_collectable {
+            return Err(syn::Error::new_spanned(
+                input,
+                "collectable cannot be combined with min_len, max_len, charset or pattern, since its Extend impls append without validation.",
+            ));
+        }
+        if let Some(default) = &attributes.default {
+            if attributes.skipped_traits.contains(&SkippableTrait::Default) {
+                return Err(syn::Error::new_spanned(
+                    default,
+                    "default cannot be combined with skip(Default).",
+                ));
+            }
+            validate_default(default, constraints.as_ref(), normalize.as_ref())?;
+        }
+
+        let public: syn::Visibility = syn::parse_quote!(pub);
+        let access = Access {
+            constructor: attributes
+                .constructor_vis
+                .map_or_else(|| public.clone(), |(_, visibility)| visibility),
+            accessor: attributes
+                .accessor_vis
+                .map_or(public, |(_, visibility)| visibility),
+            // Mutating a normalized or constrained value in place would skip `new`
+            mutable: !attributes.immutable && normalize.is_none() && constraints.is_none(),
+        };
+
+        let delta_type = match (attributes.has_point, attributes.delta_type) {
+            (true, Some(delta_type)) => {
+                if attributes.auto_operator_mode != AutoOperatorMode::None
+                    || attributes.has_addable
+                    || attributes.operator_selection.is_some()
+                {
+                    return Err(syn::Error::new_spanned(
+                        input,
+                        "point types cannot be combined with auto_operators, addable or ops, since adding two points is meaningless. Put those attributes on the delta type instead.",
+                    ));
+                }
+                if !is_numeric {
+                    return Err(syn::Error::new_spanned(
+                        input,
+                        "point is only supported for numeric strong types.",
+                    ));
+                }
+                Some(delta_type)
+            }
+            (true, None) => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "point requires a delta type. Example: #[strong_type(point, delta = TimestampDelta)]",
+                ));
+            }
+            (false, Some(delta_type)) => {
+                return Err(syn::Error::new_spanned(
+                    delta_type,
+                    "delta is only valid for point types. Example: #[strong_type(point, delta = TimestampDelta)]",
+                ));
+            }
+            (false, None) => None,
+        };
+
+        let mut operators = match &attributes.operator_selection {
+            Some(selection) => {
+                let mut operators = OperatorSet::default();
+                for (operator, path) in selection {
+                    if !operator.supports(&type_group) {
+                        return Err(syn::Error::new_spanned(
+                            path,
+                            format!(
+                                "Operator '{}' is not supported for strong types wrapping '{}'.",
+                                operator.keyword(),
+                                attributes.type_info.primitive_type.to_token_stream()
+                            ),
+                        ));
+                    }
+                    operators.insert(*operator);
+                }
+                operators
+            }
+            None => OperatorSet::auto(&type_group, attributes.auto_operator_mode),
+        };
+        if attributes.has_addable {
+            operators.extend_supported(&type_group, &[Operator::Add, Operator::Sub, Operator::Neg]);
+        }
+        if attributes.has_scalable {
+            operators.extend_supported(&type_group, &[Operator::Neg]);
+        }
+
+        let bitflags = match attributes.bitflags {
+            Some((path, flags)) => {
+                if !matches!(
+                    type_group,
+                    ValueTypeGroup::Int(UnderlyingType::Primitive)
+                        | ValueTypeGroup::UInt(UnderlyingType::Primitive)
+                ) {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "bitflags is only supported for strong types wrapping primitive integers.",
+                    ));
+                }
+                operators.extend_supported(
+                    &type_group,
+                    &[
+                        Operator::Not,
+                        Operator::BitAnd,
+                        Operator::BitOr,
+                        Operator::BitXor,
+                    ],
+                );
+                Some(flags)
+            }
+            None => None,
+        };
+
+        if let Some(shift_rhs) = &attributes.shift_rhs {
+            if !shift_ops::SHIFT_TYPES.contains(&shift_rhs.to_string().as_str()) {
+                return Err(syn::Error::new_spanned(
+                    shift_rhs,
+                    format!(
+                        "Unsupported shift_rhs type '{}'. Supported types are: {}",
+                        shift_rhs,
+                        shift_ops::SHIFT_TYPES.join(", ")
+                    ),
+                ));
+            }
+            if !operators.contains(Operator::Shl) && !operators.contains(Operator::Shr) {
+                return Err(syn::Error::new_spanned(
+                    shift_rhs,
+                    "shift_rhs requires shift operators. Enable them with auto_operators or ops(shl, shr).",
+                ));
+            }
+        }
+
         Ok(Self {
             name: input.ident.clone(),
+            vis: input.vis.clone(),
             value_type: attributes.type_info.value_type,
             primitive_type: attributes.type_info.primitive_type,
             type_group,
             auto_operator_mode: attributes.auto_operator_mode,
+            inline_mode: attributes.inline_mode,
             has_addable: attributes.has_addable,
+            operators,
+            has_addable_primitive: attributes.has_addable_primitive,
             has_scalable: attributes.has_scalable,
-            has_custom_display: attributes.has_custom_display,
+            skipped_traits: attributes.skipped_traits,
             has_conversion: attributes.has_conversion,
+            has_collectable: attributes.has_collectable,
+            shift_types: shift_types(attributes.shift_rhs.as_ref()),
+            bitflags,
+            normalize,
+            constraints,
+            borrowed_type: attributes.borrowed_type,
+            delta_type,
+            crate_path: attributes.crate_path,
+            access,
+            default: attributes.default,
         })
     }
 }
@@ -81,16 +602,41 @@ impl TypeMetadata {
 pub(crate) fn get_attributes(input: &DeriveInput) -> Result<StrongTypeAttributes, syn::Error> {
     let mut attributes = StrongTypeAttributes {
         auto_operator_mode: AutoOperatorMode::None,
-        has_custom_display: false,
+        inline_mode: InlineMode::Default,
+        skipped_traits: BTreeSet::new(),
         has_conversion: false,
         has_addable: false,
+        has_addable_primitive: false,
         has_scalable: false,
+        has_collectable: false,
+        has_point: false,
+        operator_selection: None,
+        bitflags: None,
+        shift_rhs: None,
+        normalize: None,
+        min_len: None,
+        max_len: None,
+        charset: None,
+        pattern: None,
+        borrowed_type: None,
+        delta_type: None,
+        crate_path: default_crate_path(),
+        constructor_vis: None,
+        accessor_vis: None,
+        immutable: false,
+        default: None,
         type_info: get_type(input)?,
     };
+    let mut has_underlying = false;
+    let mut primitive: Option<syn::Type> = None;
+    let mut is_opaque = false;
+
+    let mut seen = BTreeSet::new();
 
     for attr in input.attrs.iter() {
         if attr.path().is_ident("strong_type") {
             attr.parse_nested_meta(|meta| {
+                check_duplicate(&meta, &mut seen, &STRUCT_ATTRIBUTES.map(|(name, _)| name))?;
                 if meta.path.is_ident("auto_operators") {
                     // Check if there's a value assignment
                     if meta.input.peek(syn::Token![=]) {
@@ -100,9 +646,11 @@ pub(crate) fn get_attributes(input: &DeriveInput) -> Result<StrongTypeAttributes
                             "minimal" => attributes.auto_operator_mode = AutoOperatorMode::Minimal,
                             "full" => attributes.auto_operator_mode = AutoOperatorMode::Full,
                             "delegated" => attributes.auto_operator_mode = AutoOperatorMode::Delegated,
+                            "minimal_delegated" => attributes.auto_operator_mode = AutoOperatorMode::MinimalDelegated,
                             other => return Err(meta.error(format!(
-                                "Invalid auto_operators value '{}'. Valid values are: 'minimal', 'full', 'delegated'",
-                                other
+                                "Invalid auto_operators value '{}'.{} Valid values are: 'minimal', 'full', 'delegated', 'minimal_delegated'",
+                                other,
+                                did_you_mean(closest(other, AUTO_OPERATOR_MODES))
                             ))),
                         }
                     } else {
@@ -110,6 +658,118 @@ pub(crate) fn get_attributes(input: &DeriveInput) -> Result<StrongTypeAttributes
                         attributes.auto_operator_mode = AutoOperatorMode::Full;
                     }
                     Ok(())
+                } else if meta.path.is_ident("inline") {
+                    let value: syn::LitStr = meta
+                        .value()
+                        .map_err(|_| meta.error("Expected syntax like #[strong_type(inline = \"always\")]."))?
+                        .parse()?;
+                    attributes.inline_mode = match value.value().as_str() {
+                        "always" => InlineMode::Always,
+                        "never" => InlineMode::Never,
+                        "default" => InlineMode::Default,
+                        other => return Err(syn::Error::new_spanned(value, format!(
+                            "Invalid inline value '{}'.{} Valid values are: 'always', 'never', 'default'",
+                            other,
+                            did_you_mean(closest(other, ["always", "never", "default"]))
+                        ))),
+                    };
+                    Ok(())
+                } else if meta.path.is_ident("tagged") {
+                    Err(meta.error("tagged is only supported for fieldless enums."))
+                } else if meta.path.is_ident("ops") {
+                    let mut selection = Vec::new();
+                    meta.parse_nested_meta(|op| {
+                        let operator = op
+                            .path
+                            .get_ident()
+                            .and_then(|ident| Operator::from_keyword(&ident.to_string()))
+                            .ok_or_else(|| {
+                                let keyword = op.path.to_token_stream().to_string();
+                                op.error(format!(
+                                    "Unknown operator '{}'.{} Valid operators are: {}",
+                                    keyword,
+                                    did_you_mean(closest(&keyword, Operator::ALL.map(Operator::keyword))),
+                                    Operator::keywords()
+                                ))
+                            })?;
+                        selection.push((operator, op.path.clone()));
+                        Ok(())
+                    })?;
+                    if selection.is_empty() {
+                        return Err(meta.error("ops requires at least one operator. Example: #[strong_type(ops(add, sub))]"));
+                    }
+                    attributes.operator_selection = Some(selection);
+                    Ok(())
+                } else if meta.path.is_ident("bitflags") {
+                    let mut flags = Vec::new();
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|flag| {
+                            let flag_name = flag.path.require_ident()?.clone();
+                            let value = flag
+                                .value()
+                                .map_err(|_| flag.error("Expected syntax like #[strong_type(bitflags(READ = 1, WRITE = 2))]."))?;
+                            flags.push((flag_name, value.parse()?));
+                            Ok(())
+                        })?;
+                    }
+                    attributes.bitflags = Some((meta.path.clone(), flags));
+                    Ok(())
+                } else if meta.path.is_ident("normalize") {
+                    let value_stream = meta
+                        .value()
+                        .map_err(|_| meta.error("Expected syntax like #[strong_type(normalize = \"lowercase\")]."))?;
+                    let normalization = if value_stream.peek(syn::LitStr) {
+                        let value: syn::LitStr = value_stream.parse()?;
+                        match value.value().as_str() {
+                            "lowercase" => Normalization::Lowercase,
+                            "trim" => Normalization::Trim,
+                            other => return Err(syn::Error::new_spanned(value, format!(
+                                "Invalid normalize value '{}'.{} Valid values are: 'lowercase', 'trim', or a path to a fn(&str) -> String",
+                                other,
+                                did_you_mean(closest(other, ["lowercase", "trim"]))
+                            ))),
+                        }
+                    } else {
+                        Normalization::Function(value_stream.parse()?)
+                    };
+                    attributes.normalize = Some((meta.path.clone(), normalization));
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    attributes.crate_path = parse_crate_path(&meta)?;
+                    Ok(())
+                } else if meta.path.is_ident("constructor") {
+                    attributes.constructor_vis = Some(parse_visibility(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("accessor") {
+                    attributes.accessor_vis = Some(parse_visibility(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    attributes.default = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("immutable") {
+                    attributes.immutable = true;
+                    Ok(())
+                } else if meta.path.is_ident("min_len") {
+                    attributes.min_len = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("max_len") {
+                    attributes.max_len = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("charset") {
+                    attributes.charset = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("pattern") {
+                    attributes.pattern = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("shift_rhs") {
+                    let value_stream = meta
+                        .value()
+                        .map_err(|_| meta.error("Expected syntax like #[strong_type(shift_rhs = u32)]."))?;
+                    attributes.shift_rhs = Some(value_stream.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("addable_primitive") {
+                    attributes.has_addable_primitive = true;
+                    Ok(())
                 } else if meta.path.is_ident("addable") {
                     attributes.has_addable = true;
                     Ok(())
@@ -117,11 +777,30 @@ pub(crate) fn get_attributes(input: &DeriveInput) -> Result<StrongTypeAttributes
                     attributes.has_scalable = true;
                     Ok(())
                 } else if meta.path.is_ident("custom_display") {
-                    attributes.has_custom_display = true;
-                    Ok(())
+                    skip_display(&meta, &mut attributes.skipped_traits)
+                } else if meta.path.is_ident("skip") {
+                    parse_skip(&meta, &mut attributes.skipped_traits)
                 } else if meta.path.is_ident("conversion") {
                     attributes.has_conversion = true;
                     Ok(())
+                } else if meta.path.is_ident("collectable") {
+                    attributes.has_collectable = true;
+                    Ok(())
+                } else if meta.path.is_ident("point") {
+                    attributes.has_point = true;
+                    Ok(())
+                } else if meta.path.is_ident("delta") {
+                    let value_stream = meta
+                        .value()
+                        .map_err(|_| meta.error("Expected syntax like #[strong_type(point, delta = TimestampDelta)]."))?;
+                    attributes.delta_type = Some(value_stream.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("borrowed") {
+                    let value_stream = meta
+                        .value()
+                        .map_err(|_| meta.error("Expected syntax like #[strong_type(borrowed = TagStr)]."))?;
+                    attributes.borrowed_type = Some(value_stream.parse()?);
+                    Ok(())
                 } else if meta.path.is_ident("underlying") {
                     let value_stream = meta
                         .value()
@@ -129,46 +808,558 @@ pub(crate) fn get_attributes(input: &DeriveInput) -> Result<StrongTypeAttributes
                     let primitive_path: syn::Path = value_stream
                         .parse()
                         .map_err(|_| meta.error("Failed to parse underlying path. Use primitives such as i32 or core::primitive::i32."))?;
-                    let primitive_ident = primitive_path
-                        .segments
-                        .last()
-                        .ok_or_else(|| meta.error("underlying attribute must reference a primitive type."))?
-                        .ident
-                        .clone();
-
-                    let type_group = get_type_group(&primitive_ident, UnderlyingType::Derived)
+                    let type_group = get_path_type_group(&primitive_path, UnderlyingType::Derived)
+                        .filter(|type_group| !matches!(type_group, ValueTypeGroup::CowStr))
                         .ok_or_else(|| {
                             meta.error(format!(
                                 "Unsupported underlying primitive '{}'. Supported primitives are: {}",
-                                primitive_ident, SUPPORTED_PRIMITIVES
+                                compact_tokens(&primitive_path), SUPPORTED_PRIMITIVES
                             ))
                         })?;
 
+                    has_underlying = true;
                     attributes.type_info.type_group = Some(type_group);
-                    attributes.type_info.primitive_type = primitive_ident;
+                    attributes.type_info.primitive_type = syn::Type::Path(syn::TypePath {
+                        qself: None,
+                        path: primitive_path,
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("opaque") {
+                    is_opaque = true;
+                    Ok(())
+                } else if meta.path.is_ident("primitive") {
+                    let value_stream = meta
+                        .value()
+                        .map_err(|_| meta.error("Expected syntax like #[strong_type(primitive = i32)]."))?;
+                    primitive = Some(value_stream.parse()?);
                     Ok(())
                 } else {
                     let attr_name = meta.path.to_token_stream().to_string();
                     Err(meta.error(format!(
-                        "Unknown strong_type attribute '{}'. Valid attributes are: auto_operators, addable, scalable, custom_display, conversion, underlying=<type>",
-                        attr_name
+                        "Unknown strong_type attribute '{}'.{} Valid attributes are: {}",
+                        attr_name,
+                        did_you_mean(suggest_attribute(&attr_name)),
+                        STRUCT_ATTRIBUTES.map(|(_, syntax)| syntax).join(", ")
                     )))
                 }
             })?;
         }
     }
+
+    if is_opaque {
+        if has_underlying || primitive.is_some() {
+            return Err(syn::Error::new_spanned(
+                input,
+                "opaque cannot be combined with underlying or primitive, since opaque types have no primitive type.",
+            ));
+        }
+        attributes.type_info.type_group = Some(ValueTypeGroup::Opaque);
+        attributes.type_info.primitive_type = attributes.type_info.value_type.clone();
+    }
+
+    if let Some(primitive) = primitive {
+        if has_underlying {
+            return Err(syn::Error::new_spanned(
+                primitive,
+                "primitive cannot be combined with underlying. Use underlying for nested strong types and primitive for type aliases.",
+            ));
+        }
+        if !matches!(
+            attributes.type_info.type_group,
+            Some(ValueTypeGroup::Nested)
+        ) {
+            return Err(syn::Error::new_spanned(
+                primitive,
+                format!(
+                    "primitive is only needed for type aliases, and '{}' is not one.",
+                    compact_tokens(&attributes.type_info.value_type)
+                ),
+            ));
+        }
+        let type_group = resolve_type_group(&primitive).ok_or_else(|| {
+            syn::Error::new_spanned(
+                &primitive,
+                format!(
+                    "Unsupported primitive '{}'. Supported types are: {}, Cow<'static, str>",
+                    compact_tokens(&primitive),
+                    SUPPORTED_PRIMITIVES
+                ),
+            )
+        })?;
+        attributes.type_info.type_group = Some(type_group);
+        attributes.type_info.primitive_type = primitive;
+    } else if matches!(
+        attributes.type_info.type_group,
+        Some(ValueTypeGroup::Nested)
+    ) {
+        let value_type = &attributes.type_info.value_type;
+        let crate_path = &attributes.crate_path;
+        attributes.type_info.primitive_type =
+            syn::parse_quote!(<#value_type as #crate_path::StrongType>::PrimitiveType);
+    }
     Ok(attributes)
 }
 
-pub(crate) fn validate_struct(input: &DeriveInput) -> Result<(), syn::Error> {
-    if let Data::Struct(data_struct) = &input.data
-        && let Fields::Unnamed(fields_unnamed) = &data_struct.fields
-        && fields_unnamed.unnamed.len() == 1
-    {
+/// Formats a type or path for error messages, without the spaces between tokens
+fn compact_tokens(tokens: &impl ToTokens) -> String {
+    tokens.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Parses `skip(Trait, ...)` into the set of skipped trait impls
+fn parse_skip(
+    meta: &syn::meta::ParseNestedMeta,
+    skipped_traits: &mut BTreeSet<SkippableTrait>,
+) -> Result<(), syn::Error> {
+    meta.parse_nested_meta(|skip| {
+        let skippable = skip
+            .path
+            .get_ident()
+            .and_then(|ident| SkippableTrait::from_name(&ident.to_string()))
+            .ok_or_else(|| {
+                let trait_name = skip.path.to_token_stream().to_string();
+                skip.error(format!(
+                    "Unknown trait '{}' in skip(...).{} Skippable traits are: {}",
+                    trait_name,
+                    did_you_mean(closest(
+                        &trait_name,
+                        SkippableTrait::ALL.map(SkippableTrait::name)
+                    )),
+                    SkippableTrait::names()
+                ))
+            })?;
+        if !skipped_traits.insert(skippable) {
+            let shorthand = match skippable {
+                SkippableTrait::Display => " custom_display is shorthand for skip(Display).",
+                _ => "",
+            };
+            return Err(skip.error(format!(
+                "{} is already skipped.{}",
+                skippable.name(),
+                shorthand
+            )));
+        }
+        Ok(())
+    })
+}
+
+/// Parses `custom_display`, which is shorthand for `skip(Display)`
+fn skip_display(
+    meta: &syn::meta::ParseNestedMeta,
+    skipped_traits: &mut BTreeSet<SkippableTrait>,
+) -> Result<(), syn::Error> {
+    if !skipped_traits.insert(SkippableTrait::Display) {
+        return Err(meta.error(
+            "Display is already skipped, and custom_display is shorthand for skip(Display).",
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects an attribute given more than once, including across several `#[strong_type]`
+/// attributes. Unknown attributes are left to the caller.
+fn check_duplicate(
+    meta: &syn::meta::ParseNestedMeta,
+    seen: &mut BTreeSet<String>,
+    attributes: &[&str],
+) -> Result<(), syn::Error> {
+    if let Some(ident) = meta.path.get_ident() {
+        let name = ident.to_string();
+        if attributes.contains(&name.as_str()) && !seen.insert(name) {
+            return Err(meta.error(format!("Duplicate strong_type attribute '{}'.", ident)));
+        }
+    }
+    Ok(())
+}
+
+/// Suggests a valid attribute for a misspelled one. Operator modes written as attributes, such
+/// as `minimal_operators`, point to the matching `auto_operators` value.
+fn suggest_attribute(name: &str) -> Option<String> {
+    let mode = name.strip_suffix("_operators").unwrap_or(name);
+    match mode {
+        "full" | "auto" => Some("auto_operators".to_string()),
+        _ if AUTO_OPERATOR_MODES.contains(&mode) => Some(format!("auto_operators = \"{}\"", mode)),
+        _ => closest(name, STRUCT_ATTRIBUTES.map(|(name, _)| name)).map(str::to_string),
+    }
+}
+
+/// Parses `constructor = "pub(crate)"` or `accessor = "pub(crate)"`; an empty string means private
+fn parse_visibility(
+    meta: &syn::meta::ParseNestedMeta,
+) -> Result<(syn::LitStr, syn::Visibility), syn::Error> {
+    let literal: syn::LitStr = meta.value()?.parse()?;
+    let visibility = literal.parse()?;
+    Ok((literal, visibility))
+}
+
+/// Checks a string literal default against the constraints at compile time, after applying the
+/// built-in normalizations. Other defaults are validated when `Default::default` is called.
+fn validate_default(
+    default: &syn::Expr,
+    constraints: Option<&StringConstraints>,
+    normalize: Option<&Normalization>,
+) -> Result<(), syn::Error> {
+    let (
+        Some(constraints),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(literal),
+            ..
+        }),
+    ) = (constraints, default)
+    else {
         return Ok(());
     };
+    let value = match normalize {
+        None => literal.value(),
+        Some(Normalization::Lowercase) => literal.value().to_lowercase(),
+        Some(Normalization::Trim) => literal.value().trim().to_owned(),
+        Some(Normalization::Function(_)) => return Ok(()),
+    };
+    constraints.check(&value).map_err(|reason| {
+        syn::Error::new_spanned(literal, format!("Invalid default value: {}", reason))
+    })
+}
+
+/// The attribute literal of a visibility other than `pub`, for error spans
+fn restricted_visibility(
+    visibility: &Option<(syn::LitStr, syn::Visibility)>,
+) -> Option<&syn::LitStr> {
+    visibility
+        .as_ref()
+        .filter(|(_, visibility)| !matches!(visibility, syn::Visibility::Public(_)))
+        .map(|(literal, _)| literal)
+}
+
+/// Checks the regex syntax at compile time, so invalid patterns do not panic on first use
+#[cfg(feature = "regex")]
+fn validate_pattern(pattern: &syn::LitStr) -> Result<(), syn::Error> {
+    regex_syntax::Parser::new()
+        .parse(&pattern.value())
+        .map(|_| ())
+        .map_err(|error| syn::Error::new_spanned(pattern, format!("Invalid pattern: {}", error)))
+}
+
+#[cfg(not(feature = "regex"))]
+fn validate_pattern(pattern: &syn::LitStr) -> Result<(), syn::Error> {
+    Err(syn::Error::new_spanned(
+        pattern,
+        "pattern requires the `regex` feature of strong-type.",
+    ))
+}
+
+pub(crate) fn validate_struct(input: &DeriveInput) -> Result<(), syn::Error> {
+    match &input.data {
+        Data::Struct(data_struct) => {
+            if let Fields::Unnamed(fields_unnamed) = &data_struct.fields
+                && fields_unnamed.unnamed.len() == 1
+            {
+                return Ok(());
+            }
+        }
+        Data::Enum(data_enum) if is_tagged(input)? => {
+            return validate_tagged_enum(input, data_enum);
+        }
+        _ => {}
+    }
     Err(syn::Error::new_spanned(
         input,
-        "StrongType can only be derived for tuple structs with exactly one field. Example: struct MyType(i32);",
+        "StrongType can only be derived for tuple structs with exactly one field, or for fieldless enums with #[strong_type(tagged)] and #[repr(<integer>)]. Example: struct MyType(i32);",
     ))
 }
+
+/// Whether the `strong_type` attributes contain `tagged`, skipping over all other attributes
+fn is_tagged(input: &DeriveInput) -> Result<bool, syn::Error> {
+    let mut tagged = false;
+    for attr in input.attrs.iter() {
+        if attr.path().is_ident("strong_type") {
+            attr.parse_nested_meta(|meta| {
+                tagged |= meta.path.is_ident("tagged");
+                while !meta.input.is_empty() && !meta.input.peek(syn::Token![,]) {
+                    meta.input.parse::<proc_macro2::TokenTree>()?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(tagged)
+}
+
+fn validate_tagged_enum(input: &DeriveInput, data_enum: &DataEnum) -> Result<(), syn::Error> {
+    if data_enum.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            input,
+            "tagged enums must have at least one variant.",
+        ));
+    }
+    if let Some(variant) = data_enum
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "tagged enums can only have fieldless variants.",
+        ));
+    }
+    Ok(())
+}
+
+/// How a tagged enum is displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TaggedDisplay {
+    /// The variant name, e.g. `NotFound`
+    Name,
+    /// The discriminant value, e.g. `404`
+    Value,
+}
+
+/// Metadata about a fieldless enum deriving StrongType with `#[strong_type(tagged)]`
+pub(crate) struct TaggedMetadata {
+    /// The name of the enum
+    pub name: syn::Ident,
+    /// The integer type from `#[repr(...)]`
+    pub primitive_type: syn::Type,
+    /// The variants, in declaration order
+    pub variants: Vec<syn::Ident>,
+    /// How the generated Display implementation formats a variant
+    pub display: TaggedDisplay,
+    /// The generated trait impls the user opted out of with skip(...) or custom_display
+    pub skipped_traits: BTreeSet<SkippableTrait>,
+    /// The path to the strong_type crate, `::strong_type` unless it is re-exported
+    pub crate_path: syn::Path,
+}
+
+impl TaggedMetadata {
+    pub fn new(input: &DeriveInput, data_enum: &DataEnum) -> Result<Self, syn::Error> {
+        let mut display = TaggedDisplay::Name;
+        let mut skipped_traits = BTreeSet::new();
+        let mut crate_path = default_crate_path();
+        let mut seen = BTreeSet::new();
+
+        for attr in input.attrs.iter() {
+            if attr.path().is_ident("strong_type") {
+                attr.parse_nested_meta(|meta| {
+                    check_duplicate(&meta, &mut seen, &TAGGED_ATTRIBUTES)?;
+                    if meta.path.is_ident("tagged") {
+                        if meta.input.peek(syn::token::Paren) {
+                            meta.parse_nested_meta(|option| {
+                                if !option.path.is_ident("display") {
+                                    return Err(option.error("Expected syntax like #[strong_type(tagged(display = \"value\"))]."));
+                                }
+                                let value: syn::LitStr = option.value()?.parse()?;
+                                display = match value.value().as_str() {
+                                    "name" => TaggedDisplay::Name,
+                                    "value" => TaggedDisplay::Value,
+                                    other => return Err(syn::Error::new_spanned(value, format!(
+                                        "Invalid tagged display '{}'. Valid values are: 'name', 'value'",
+                                        other
+                                    ))),
+                                };
+                                Ok(())
+                            })?;
+                        }
+                        Ok(())
+                    } else if meta.path.is_ident("custom_display") {
+                        skip_display(&meta, &mut skipped_traits)
+                    } else if meta.path.is_ident("skip") {
+                        parse_skip(&meta, &mut skipped_traits)
+                    } else if meta.path.is_ident("crate") {
+                        crate_path = parse_crate_path(&meta)?;
+                        Ok(())
+                    } else {
+                        let attr_name = meta.path.to_token_stream().to_string();
+                        Err(meta.error(format!(
+                            "Unsupported attribute '{}' for tagged enums.{} Valid attributes are: tagged, tagged(display = \"name\" | \"value\"), custom_display, skip(...), crate = \"...\"",
+                            attr_name,
+                            did_you_mean(closest(&attr_name, TAGGED_ATTRIBUTES))
+                        )))
+                    }
+                })?;
+            }
+        }
+
+        if let Some(skipped) = [
+            SkippableTrait::Default,
+            SkippableTrait::AsRef,
+            SkippableTrait::AsMut,
+        ]
+        .iter()
+        .find(|skippable| skipped_traits.contains(skippable))
+        {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "{} is not generated for tagged enums, so it cannot be skipped.",
+                    skipped.name()
+                ),
+            ));
+        }
+
+        let mut primitive_type = None;
+        for attr in input.attrs.iter() {
+            if attr.path().is_ident("repr") {
+                attr.parse_nested_meta(|meta| {
+                    if let Some(ident) = meta.path.get_ident()
+                        && matches!(
+                            get_type_group(ident, UnderlyingType::Primitive),
+                            Some(ValueTypeGroup::Int(_) | ValueTypeGroup::UInt(_))
+                        )
+                    {
+                        primitive_type = Some(syn::parse_quote!(#ident));
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+        let primitive_type = primitive_type.ok_or_else(|| {
+            syn::Error::new_spanned(
+                &input.ident,
+                "tagged enums require an integer representation. Example: #[repr(u8)]",
+            )
+        })?;
+
+        Ok(Self {
+            name: input.ident.clone(),
+            primitive_type,
+            variants: data_enum
+                .variants
+                .iter()
+                .map(|variant| variant.ident.clone())
+                .collect(),
+            display,
+            skipped_traits,
+            crate_path,
+        })
+    }
+}
+
+/// The attributes of `#[strong_type(...)]` on strong types with a lifetime parameter
+const LIFETIME_ATTRIBUTES: [&str; 5] = ["owned", "conversion", "custom_display", "skip", "crate"];
+
+/// Metadata about a strong type with a lifetime parameter, such as `struct Name<'a>(&'a str)`
+pub(crate) struct LifetimeMetadata {
+    /// The name of the strong type
+    pub name: syn::Ident,
+    /// The lifetime parameter of the strong type
+    pub lifetime: syn::Lifetime,
+    /// The wrapped borrowed type, as written
+    pub value_type: syn::Type,
+    /// Which borrowed type is wrapped
+    pub kind: BorrowedKind,
+    /// The owned strong type returned by `into_owned`, if any
+    pub owned_type: Option<syn::Path>,
+    /// Whether to generate From/Into conversion traits
+    pub has_conversion: bool,
+    /// The generated trait impls the user opted out of with skip(...) or custom_display
+    pub skipped_traits: BTreeSet<SkippableTrait>,
+    /// The path to the strong_type crate, `::strong_type` unless it is re-exported
+    pub crate_path: syn::Path,
+}
+
+impl LifetimeMetadata {
+    pub fn new(input: &DeriveInput) -> Result<Self, syn::Error> {
+        let generics = &input.generics;
+        let lifetime = match (
+            generics.lifetimes().count(),
+            generics.params.len(),
+            &generics.where_clause,
+        ) {
+            (1, 1, None) => generics
+                .lifetimes()
+                .next()
+                .map(|param| param.lifetime.clone()),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                generics,
+                "Strong types may only have a single lifetime parameter, without bounds. Example: struct Name<'a>(&'a str);",
+            )
+        })?;
+
+        let type_info = get_type(input)?;
+        let kind = get_borrowed_kind(&type_info.value_type, &lifetime).ok_or_else(|| {
+            syn::Error::new_spanned(
+                &type_info.value_type,
+                format!(
+                    "Strong types with a lifetime parameter must wrap &{0} str, &{0} [u8] or Cow<{0}, str>.",
+                    lifetime
+                ),
+            )
+        })?;
+
+        let mut owned_type = None;
+        let mut has_conversion = false;
+        let mut skipped_traits = BTreeSet::new();
+        let mut crate_path = default_crate_path();
+        let mut seen = BTreeSet::new();
+
+        for attr in input.attrs.iter() {
+            if attr.path().is_ident("strong_type") {
+                attr.parse_nested_meta(|meta| {
+                    check_duplicate(&meta, &mut seen, &LIFETIME_ATTRIBUTES)?;
+                    if meta.path.is_ident("owned") {
+                        let value_stream = meta
+                            .value()
+                            .map_err(|_| meta.error("Expected syntax like #[strong_type(owned = Name)]."))?;
+                        owned_type = Some(value_stream.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("conversion") {
+                        has_conversion = true;
+                        Ok(())
+                    } else if meta.path.is_ident("custom_display") {
+                        skip_display(&meta, &mut skipped_traits)
+                    } else if meta.path.is_ident("skip") {
+                        parse_skip(&meta, &mut skipped_traits)
+                    } else if meta.path.is_ident("crate") {
+                        crate_path = parse_crate_path(&meta)?;
+                        Ok(())
+                    } else {
+                        let attr_name = meta.path.to_token_stream().to_string();
+                        Err(meta.error(format!(
+                            "Unsupported attribute '{}' for strong types with a lifetime parameter.{} Valid attributes are: owned=<type>, conversion, custom_display, skip(...), crate=\"...\"",
+                            attr_name,
+                            did_you_mean(closest(&attr_name, LIFETIME_ATTRIBUTES))
+                        )))
+                    }
+                })?;
+            }
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        if kind == BorrowedKind::CowStr || owned_type.is_some() {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Strong types wrapping Cow<'a, str> or with an owned type require the `alloc` feature of strong-type.",
+            ));
+        }
+
+        // Bytes have no Display, and the value cannot be mutated through a shared borrow
+        let mut not_generated = vec![SkippableTrait::AsMut];
+        if kind == BorrowedKind::Bytes {
+            not_generated.push(SkippableTrait::Display);
+        }
+        if let Some(skipped) = not_generated
+            .iter()
+            .find(|skippable| skipped_traits.contains(skippable))
+        {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "{} is not generated for this strong type, so it cannot be skipped.",
+                    skipped.name()
+                ),
+            ));
+        }
+
+        Ok(Self {
+            name: input.ident.clone(),
+            lifetime,
+            value_type: type_info.value_type,
+            kind,
+            owned_type,
+            has_conversion,
+            skipped_traits,
+            crate_path,
+        })
+    }
+}
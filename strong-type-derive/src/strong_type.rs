@@ -1,19 +1,21 @@
 use crate::detail::{
-    AutoOperatorMode, TypeMetadata, UnderlyingType, ValueTypeGroup, generate_strong_type_ops_impl,
-    impl_minimal_negate, implement_addable, implement_arithmetic, implement_basic,
-    implement_basic_primitive, implement_basic_string, implement_bit_shift, implement_bool_ops,
-    implement_constants, implement_constants_derived, implement_conversion,
-    implement_delegated_arithmetic, implement_delegated_bit_shift, implement_delegated_bool_ops,
-    implement_delegated_negate, implement_delegated_scalable, implement_display, implement_hash,
-    implement_infinity, implement_limit, implement_minimal_arithmetic, implement_minimal_bool_ops,
-    implement_nan, implement_negate, implement_primitive_accessor,
-    implement_primitive_accessor_derived, implement_primitive_str_accessor,
-    implement_primitive_str_accessor_derived, implement_scalable, implement_str_conversion,
-    validate_struct,
+    AutoOperatorMode, Constructor, DefaultValue, LifetimeMetadata, Operator, OperatorAttributes,
+    SkippableTrait, TaggedMetadata, TypeMetadata, UnderlyingType, ValueTypeGroup,
+    generate_strong_type_ops_impl, implement_addable_primitive, implement_basic,
+    implement_basic_primitive, implement_basic_string, implement_bitflags, implement_borrowed,
+    implement_checked_shift, implement_constants, implement_constants_derived,
+    implement_constraints, implement_conversion, implement_count_division, implement_default,
+    implement_display, implement_float_min_max, implement_hash, implement_infinity,
+    implement_lifetime, implement_limit, implement_mean, implement_nan, implement_nested,
+    implement_nested_operators, implement_normalize, implement_opaque, implement_operators,
+    implement_point, implement_primitive_accessor, implement_primitive_accessor_derived,
+    implement_primitive_str_accessor, implement_primitive_str_accessor_derived, implement_scalable,
+    implement_static_str_constructor, implement_static_str_conversion, implement_str_conversion,
+    implement_string_collection, implement_strong_type_access, implement_tagged, validate_struct,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{Data, DeriveInput};
 
 pub(super) fn expand_strong_type(input: DeriveInput) -> TokenStream {
     match expand_strong_type_impl(input) {
@@ -25,6 +27,16 @@ pub(super) fn expand_strong_type(input: DeriveInput) -> TokenStream {
 fn expand_strong_type_impl(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     validate_struct(&input)?;
 
+    if let Data::Enum(data_enum) = &input.data {
+        let metadata = TaggedMetadata::new(&input, data_enum)?;
+        return Ok(implement_tagged(&metadata));
+    }
+
+    if !input.generics.params.is_empty() {
+        let metadata = LifetimeMetadata::new(&input)?;
+        return Ok(implement_lifetime(&metadata));
+    }
+
     // Resolve all type information once and cache it
     let metadata = TypeMetadata::new(&input)?;
 
@@ -33,28 +45,128 @@ fn expand_strong_type_impl(input: DeriveInput) -> Result<TokenStream, syn::Error
     let primitive_type = &metadata.primitive_type;
     let type_group = &metadata.type_group;
     let auto_operator_mode = metadata.auto_operator_mode;
-    let has_addable = metadata.has_addable;
+    let has_addable_primitive = metadata.has_addable_primitive;
     let has_scalable = metadata.has_scalable;
-    let has_custom_display = metadata.has_custom_display;
     let has_conversion = metadata.has_conversion;
+    let has_collectable = metadata.has_collectable;
+    let borrowed_type = &metadata.borrowed_type;
+    let delta_type = &metadata.delta_type;
+    let inline = metadata.inline_mode;
+    let skipped = &metadata.skipped_traits;
+    let crate_path = &metadata.crate_path;
+    let operator_attributes = OperatorAttributes {
+        inline,
+        // Integer arithmetic may panic on overflow or division by zero; report the caller instead.
+        // The inner type of a nested type may be an integer as well.
+        track_caller: matches!(
+            type_group,
+            ValueTypeGroup::Int(_) | ValueTypeGroup::UInt(_) | ValueTypeGroup::Nested
+        ),
+    };
 
     let mut ast = quote!();
-    ast.extend(implement_basic(name, value_type, primitive_type));
+    let constructor = match (&metadata.constraints, &metadata.normalize) {
+        (Some(_), _) => Constructor::Validated,
+        (None, Some(_)) => Constructor::Normalized,
+        (None, None) => Constructor::Plain,
+    };
+    ast.extend(implement_basic(&metadata, constructor));
+    if let Some(normalization) = &metadata.normalize {
+        ast.extend(implement_normalize(
+            name,
+            normalization,
+            metadata.constraints.is_none() && metadata.access.has_public_constructor(),
+            crate_path,
+            inline,
+        ));
+    }
+    let default = match &metadata.default {
+        Some(expr) => Some(DefaultValue::Expr {
+            expr,
+            is_const: matches!(
+                type_group,
+                ValueTypeGroup::Int(UnderlyingType::Primitive)
+                    | ValueTypeGroup::UInt(UnderlyingType::Primitive)
+                    | ValueTypeGroup::Float(UnderlyingType::Primitive)
+                    | ValueTypeGroup::Bool(UnderlyingType::Primitive)
+                    | ValueTypeGroup::Char(UnderlyingType::Primitive)
+            ),
+        }),
+        // An empty or zero value may violate the constraints, so validated types have no Default
+        None if constructor == Constructor::Validated => None,
+        // A public Default would construct values despite a restricted constructor
+        None if !metadata.access.has_public_constructor() => None,
+        None => Some(DefaultValue::Inner),
+    };
+    if let Some(default) = default
+        && !skipped.contains(&SkippableTrait::Default)
+    {
+        ast.extend(implement_default(name, value_type, default, inline));
+    }
+    if let Some(constraints) = &metadata.constraints {
+        ast.extend(implement_constraints(
+            name,
+            value_type,
+            constraints,
+            metadata.normalize.is_some(),
+            &metadata.access.constructor,
+            crate_path,
+            inline,
+        ));
+    }
 
     // Generate StrongTypeOps trait implementation if operators will be generated
-    // This trait is required for delegated operators to work
-    if auto_operator_mode == AutoOperatorMode::Delegated || has_addable || has_scalable {
-        ast.extend(generate_strong_type_ops_impl(name, primitive_type));
+    // This trait is required for delegated operators to work. Its `from_primitive` constructs
+    // values publicly, so restricted constructors, which reject delegation, leave it out.
+    // Nested types forward their operators to the inner type and have no primitive to delegate.
+    if !matches!(type_group, ValueTypeGroup::Nested)
+        && (matches!(
+            auto_operator_mode,
+            AutoOperatorMode::Delegated | AutoOperatorMode::MinimalDelegated
+        ) || ((metadata.has_addable || has_addable_primitive || has_scalable)
+            && metadata.access.has_public_constructor()))
+    {
+        ast.extend(generate_strong_type_ops_impl(
+            name,
+            primitive_type,
+            crate_path,
+        ));
     }
 
-    if !has_custom_display {
-        ast.extend(implement_display(name));
-    };
+    // Nested and opaque types bound Display on the inner type instead
+    if !matches!(type_group, ValueTypeGroup::Nested | ValueTypeGroup::Opaque)
+        && !skipped.contains(&SkippableTrait::Display)
+    {
+        ast.extend(implement_display(name, inline));
+    }
 
     if has_conversion {
-        ast.extend(implement_conversion(name, value_type));
-        if let ValueTypeGroup::String(UnderlyingType::Primitive) = &type_group {
-            ast.extend(implement_str_conversion(name));
+        ast.extend(implement_conversion(
+            name,
+            value_type,
+            metadata.access.mutable,
+            constructor == Constructor::Validated,
+            crate_path,
+            inline,
+        ));
+        match &type_group {
+            ValueTypeGroup::String(UnderlyingType::Primitive) => {
+                ast.extend(implement_str_conversion(
+                    name,
+                    constructor == Constructor::Validated,
+                    crate_path,
+                    inline,
+                ));
+            }
+            ValueTypeGroup::CowStr => {
+                ast.extend(implement_static_str_conversion(
+                    name,
+                    constructor == Constructor::Validated,
+                    crate_path,
+                    inline,
+                ));
+            }
+            _ => {}
         }
     }
 
@@ -64,23 +176,51 @@ fn expand_strong_type_impl(input: DeriveInput) -> Result<TokenStream, syn::Error
         | ValueTypeGroup::Float(underlying_type)
         | ValueTypeGroup::Bool(underlying_type)
         | ValueTypeGroup::Char(underlying_type) => match underlying_type {
-            UnderlyingType::Primitive => ast.extend(implement_primitive_accessor(name, value_type)),
-            UnderlyingType::Derived => {
-                ast.extend(implement_primitive_accessor_derived(name, primitive_type))
+            UnderlyingType::Primitive => {
+                ast.extend(implement_primitive_accessor(name, value_type, inline))
             }
+            UnderlyingType::Derived => ast.extend(implement_primitive_accessor_derived(
+                name,
+                primitive_type,
+                inline,
+            )),
         },
-        ValueTypeGroup::String(UnderlyingType::Primitive) => {
-            ast.extend(implement_primitive_str_accessor(name));
+        ValueTypeGroup::String(UnderlyingType::Primitive) | ValueTypeGroup::CowStr => {
+            ast.extend(implement_primitive_str_accessor(name, inline));
         }
         ValueTypeGroup::String(UnderlyingType::Derived) => {
-            ast.extend(implement_primitive_str_accessor_derived(name, value_type));
+            ast.extend(implement_primitive_str_accessor_derived(
+                name, value_type, inline,
+            ));
+        }
+        ValueTypeGroup::Nested => {
+            ast.extend(implement_nested(
+                name, value_type, skipped, crate_path, inline,
+            ));
+        }
+        ValueTypeGroup::Opaque => {
+            ast.extend(implement_opaque(name, value_type, skipped, inline));
         }
     }
+    ast.extend(implement_strong_type_access(
+        name,
+        value_type,
+        primitive_type,
+        type_group,
+        crate_path,
+        inline,
+    ));
 
     match &type_group {
         ValueTypeGroup::Int(underlying_type) | ValueTypeGroup::UInt(underlying_type) => {
-            ast.extend(implement_basic_primitive(name, value_type));
-            ast.extend(implement_hash(name));
+            ast.extend(implement_basic_primitive(
+                name,
+                value_type,
+                &metadata.access.constructor,
+                skipped,
+                inline,
+            ));
+            ast.extend(implement_hash(name, skipped, inline));
             ast.extend(implement_limit(name, value_type));
             match underlying_type {
                 UnderlyingType::Primitive => ast.extend(implement_constants(name, value_type)),
@@ -90,8 +230,15 @@ fn expand_strong_type_impl(input: DeriveInput) -> Result<TokenStream, syn::Error
             }
         }
         ValueTypeGroup::Float(underlying_type) => {
-            ast.extend(implement_basic_primitive(name, value_type));
-            ast.extend(implement_nan(name, value_type));
+            ast.extend(implement_basic_primitive(
+                name,
+                value_type,
+                &metadata.access.constructor,
+                skipped,
+                inline,
+            ));
+            ast.extend(implement_float_min_max(name, inline));
+            ast.extend(implement_nan(name, value_type, inline));
             ast.extend(implement_limit(name, value_type));
             ast.extend(implement_infinity(name, value_type));
             match underlying_type {
@@ -104,129 +251,166 @@ fn expand_strong_type_impl(input: DeriveInput) -> Result<TokenStream, syn::Error
             }
         }
         ValueTypeGroup::Bool(_) => {
-            ast.extend(implement_basic_primitive(name, value_type));
-            ast.extend(implement_hash(name));
+            ast.extend(implement_basic_primitive(
+                name,
+                value_type,
+                &metadata.access.constructor,
+                skipped,
+                inline,
+            ));
+            ast.extend(implement_hash(name, skipped, inline));
         }
         ValueTypeGroup::Char(_) => {
-            ast.extend(implement_basic_primitive(name, value_type));
-            ast.extend(implement_hash(name));
+            ast.extend(implement_basic_primitive(
+                name,
+                value_type,
+                &metadata.access.constructor,
+                skipped,
+                inline,
+            ));
+            ast.extend(implement_hash(name, skipped, inline));
         }
         ValueTypeGroup::String(_) => {
-            ast.extend(implement_basic_string(name));
-            ast.extend(implement_hash(name));
-        }
-    }
-
-    // Consolidate operator implementations in a single match to avoid repeated pattern matching
-    match &type_group {
-        ValueTypeGroup::Float(_) => {
-            match auto_operator_mode {
-                AutoOperatorMode::Full => {
-                    ast.extend(implement_arithmetic(name));
-                    ast.extend(implement_negate(name));
-                }
-                AutoOperatorMode::Minimal => {
-                    ast.extend(implement_minimal_arithmetic(name));
-                    ast.extend(impl_minimal_negate(name));
-                }
-                AutoOperatorMode::Delegated => {
-                    ast.extend(implement_delegated_arithmetic(name));
-                    ast.extend(implement_delegated_negate(name));
-                }
-                AutoOperatorMode::None => {
-                    if has_addable {
-                        ast.extend(implement_addable(name));
-                        ast.extend(implement_negate(name));
-                    }
-                }
-            }
-            if has_scalable {
-                if auto_operator_mode == AutoOperatorMode::Delegated {
-                    ast.extend(implement_delegated_scalable(name, value_type));
-                } else {
-                    ast.extend(implement_scalable(name, value_type));
-                }
-                if auto_operator_mode == AutoOperatorMode::None && !has_addable {
-                    ast.extend(implement_negate(name));
-                }
+            ast.extend(implement_basic_string(name, skipped, inline));
+            ast.extend(implement_hash(name, skipped, inline));
+            if has_collectable {
+                ast.extend(implement_string_collection(
+                    name,
+                    quote!(&mut self.0),
+                    crate_path,
+                    inline,
+                ));
             }
         }
-        ValueTypeGroup::Int(_) => {
-            match auto_operator_mode {
-                AutoOperatorMode::Full => {
-                    ast.extend(implement_arithmetic(name));
-                    ast.extend(implement_negate(name));
-                    ast.extend(implement_bit_shift(name));
-                }
-                AutoOperatorMode::Minimal => {
-                    ast.extend(implement_minimal_arithmetic(name));
-                    ast.extend(impl_minimal_negate(name));
-                    // No bit shifts in minimal mode
-                }
-                AutoOperatorMode::Delegated => {
-                    ast.extend(implement_delegated_arithmetic(name));
-                    ast.extend(implement_delegated_negate(name));
-                    ast.extend(implement_delegated_bit_shift(name));
-                }
-                AutoOperatorMode::None => {
-                    if has_addable {
-                        ast.extend(implement_addable(name));
-                        ast.extend(implement_negate(name));
-                    }
-                }
+        ValueTypeGroup::CowStr => {
+            ast.extend(implement_basic_string(name, skipped, inline));
+            // A const constructor cannot normalize or validate its input
+            if metadata.normalize.is_none() && metadata.constraints.is_none() {
+                ast.extend(implement_static_str_constructor(
+                    name,
+                    &metadata.access.constructor,
+                    crate_path,
+                    inline,
+                ));
             }
-            if has_scalable {
-                if auto_operator_mode == AutoOperatorMode::Delegated {
-                    ast.extend(implement_delegated_scalable(name, value_type));
-                } else {
-                    ast.extend(implement_scalable(name, value_type));
-                }
-                if auto_operator_mode == AutoOperatorMode::None && !has_addable {
-                    ast.extend(implement_negate(name));
-                }
+            ast.extend(implement_hash(name, skipped, inline));
+            if has_collectable {
+                ast.extend(implement_string_collection(
+                    name,
+                    quote!(self.0.to_mut()),
+                    crate_path,
+                    inline,
+                ));
             }
         }
-        ValueTypeGroup::UInt(_) => {
-            match auto_operator_mode {
-                AutoOperatorMode::Full => {
-                    ast.extend(implement_arithmetic(name));
-                    ast.extend(implement_bit_shift(name));
-                }
-                AutoOperatorMode::Minimal => {
-                    ast.extend(implement_minimal_arithmetic(name));
-                    // No bit shifts in minimal mode
-                }
-                AutoOperatorMode::Delegated => {
-                    ast.extend(implement_delegated_arithmetic(name));
-                    ast.extend(implement_delegated_bit_shift(name));
-                }
-                AutoOperatorMode::None => {
-                    if has_addable {
-                        ast.extend(implement_addable(name));
-                    }
-                }
-            }
-            if has_scalable {
-                if auto_operator_mode == AutoOperatorMode::Delegated {
-                    ast.extend(implement_delegated_scalable(name, value_type));
-                } else {
-                    ast.extend(implement_scalable(name, value_type));
-                }
-            }
+        ValueTypeGroup::Nested | ValueTypeGroup::Opaque => {}
+    }
+
+    if let Some(flags) = &metadata.bitflags {
+        ast.extend(implement_bitflags(
+            name,
+            flags,
+            metadata.access.mutable,
+            inline,
+        ));
+    }
+
+    if let Some(borrowed_type) = borrowed_type {
+        ast.extend(implement_borrowed(
+            name,
+            borrowed_type,
+            &metadata.vis,
+            crate_path,
+            inline,
+        ));
+    }
+
+    if let Some(delta_type) = delta_type
+        && !matches!(type_group, ValueTypeGroup::Nested)
+    {
+        ast.extend(implement_point(name, delta_type, operator_attributes));
+    }
+
+    if has_addable_primitive
+        && let ValueTypeGroup::Int(_) | ValueTypeGroup::UInt(_) | ValueTypeGroup::Float(_) =
+            &type_group
+    {
+        ast.extend(implement_addable_primitive(
+            name,
+            value_type,
+            auto_operator_mode,
+            crate_path,
+            operator_attributes,
+        ));
+    }
+
+    // Averaging only needs addition and a count, so it comes with any selection that includes `Add`
+    let has_add = metadata.operators.contains(Operator::Add);
+    match &type_group {
+        ValueTypeGroup::Int(UnderlyingType::Primitive)
+        | ValueTypeGroup::UInt(UnderlyingType::Primitive)
+        | ValueTypeGroup::Float(UnderlyingType::Primitive)
+            if has_add =>
+        {
+            ast.extend(implement_mean(
+                name,
+                value_type,
+                primitive_type,
+                type_group,
+                inline,
+            ));
         }
-        ValueTypeGroup::Bool(_) => match auto_operator_mode {
-            AutoOperatorMode::Full => {
-                ast.extend(implement_bool_ops(name));
-            }
-            AutoOperatorMode::Minimal => {
-                ast.extend(implement_minimal_bool_ops(name));
-            }
-            AutoOperatorMode::Delegated => {
-                ast.extend(implement_delegated_bool_ops(name));
-            }
-            AutoOperatorMode::None => {}
-        },
-        ValueTypeGroup::Char(_) | ValueTypeGroup::String(_) => {}
+        _ => {}
+    }
+    // Dividing by a count comes with `Div`, and with `addable`, which has no other way to
+    // divide a sum
+    if let ValueTypeGroup::Float(UnderlyingType::Primitive) = &type_group
+        && (metadata.operators.contains(Operator::Div) || metadata.has_addable)
+    {
+        ast.extend(implement_count_division(
+            name,
+            value_type,
+            auto_operator_mode,
+            crate_path,
+            operator_attributes,
+        ));
+    }
+
+    if let ValueTypeGroup::Nested = type_group {
+        ast.extend(implement_nested_operators(&metadata, operator_attributes));
+    } else {
+        ast.extend(implement_operators(
+            name,
+            &metadata.operators,
+            &metadata.shift_types,
+            auto_operator_mode,
+            crate_path,
+            operator_attributes,
+        ));
+    }
+
+    if let ValueTypeGroup::Int(UnderlyingType::Primitive)
+    | ValueTypeGroup::UInt(UnderlyingType::Primitive) = &type_group
+    {
+        ast.extend(implement_checked_shift(
+            name,
+            metadata.operators.contains(Operator::Shl),
+            metadata.operators.contains(Operator::Shr),
+            inline,
+        ));
+    }
+
+    if has_scalable
+        && let ValueTypeGroup::Int(_) | ValueTypeGroup::UInt(_) | ValueTypeGroup::Float(_) =
+            &type_group
+    {
+        ast.extend(implement_scalable(
+            name,
+            value_type,
+            auto_operator_mode,
+            crate_path,
+            operator_attributes,
+        ));
     }
 
     Ok(ast)
@@ -0,0 +1,42 @@
+//! Strong types derived through a re-export of strong-type, using `#[strong_type(crate = "...")]`.
+
+pub mod reexport {
+    pub use facade as strong_type;
+}
+
+use reexport::strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(crate = "facade", auto_operators = "delegated", scalable)]
+pub struct Meters(f64);
+
+#[derive(StrongType)]
+#[strong_type(
+    crate = "crate::reexport::strong_type",
+    auto_operators = "minimal_delegated"
+)]
+pub struct Counter(u32);
+
+#[derive(StrongType)]
+#[strong_type(crate = "::facade", tagged)]
+#[repr(u8)]
+pub enum Level {
+    Low = 1,
+    High = 2,
+}
+
+#[derive(StrongType)]
+#[strong_type(crate = "facade", normalize = "trim", min_len = 1, pattern = "[a-z]+")]
+pub struct Word(String);
+
+#[derive(StrongType)]
+#[strong_type(crate = "facade", conversion, collectable, borrowed = NameStr)]
+pub struct Name(String);
+
+#[derive(StrongType)]
+#[strong_type(crate = "facade", owned = Name)]
+pub struct NameRef<'a>(&'a str);
+
+#[derive(StrongType)]
+#[strong_type(crate = "facade")]
+pub struct Distance(Meters);
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use facade::StrongType;
+    use strong_type_facade_tests::{
+        Counter, Distance, Level, Meters, Name, NameRef, NameStr, Word,
+    };
+
+    fn primitive<T: StrongType>(_: &T) -> &'static str {
+        std::any::type_name::<T::PrimitiveType>()
+    }
+
+    #[test]
+    fn test_crate_path_override() {
+        assert_eq!(Meters::new(1.5) * 2.0, Meters::new(3.0));
+        assert_eq!(
+            [Counter::new(1u32), Counter::new(2u32)]
+                .into_iter()
+                .sum::<Counter>(),
+            Counter::new(3u32)
+        );
+        assert_eq!(primitive(&Counter::new(1u32)), "u32");
+        assert_eq!(Level::try_from(2), Ok(Level::High));
+        assert_eq!(
+            Level::try_from(3).unwrap_err().kind(),
+            facade::ErrorKind::UnknownVariant
+        );
+        assert_eq!(" word ".parse::<Word>(), Ok(Word::new("word")));
+        assert!(Word::try_new("Word").is_err());
+        assert_eq!(NameRef::new("name").into_owned(), Name::from("name"));
+        let name = ["na", "me"].into_iter().collect::<Name>();
+        assert_eq!(NameStr::new("name").to_owned(), name);
+        assert_eq!(primitive(&Distance::new(Meters::new(1.0))), "f64");
+    }
+}
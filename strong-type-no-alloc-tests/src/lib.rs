@@ -0,0 +1,57 @@
+//! Strong types declared in a `no_std` crate without `alloc`, to check the generated code that
+//! does not depend on `alloc`. Features are unified across the workspace, so this crate only
+//! builds without `alloc` when tested on its own with `cargo test -p strong-type-no-alloc-tests`.
+
+#![no_std]
+
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+pub struct Counter(i32);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators = "minimal_delegated", scalable)]
+pub struct Meters(f64);
+
+#[derive(StrongType)]
+#[strong_type(addable, addable_primitive, conversion)]
+pub struct Duration(i64);
+
+#[derive(StrongType)]
+#[strong_type(point, delta = Duration)]
+pub struct Instant(i64);
+
+#[derive(StrongType)]
+#[strong_type(bitflags(READ = 1, WRITE = 2))]
+pub struct Permissions(u8);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+pub struct Enabled(bool);
+
+#[derive(StrongType)]
+#[strong_type(default = 'x')]
+pub struct Initial(char);
+
+#[derive(StrongType)]
+#[strong_type(tagged)]
+#[repr(u8)]
+pub enum Level {
+    Low = 1,
+    High = 2,
+}
+
+#[derive(StrongType)]
+pub struct Total(Counter);
+
+#[derive(StrongType)]
+#[strong_type(opaque)]
+pub struct Timeout(core::time::Duration);
+
+#[derive(StrongType)]
+#[strong_type(conversion)]
+pub struct NameRef<'a>(&'a str);
+
+#[derive(StrongType)]
+pub struct Payload<'a>(&'a [u8]);
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use strong_type::ErrorKind;
+    use strong_type_no_alloc_tests::*;
+
+    #[test]
+    fn test_numeric_types() {
+        assert_eq!(Counter::new(2) * Counter::new(3), Counter::new(6));
+        assert_eq!(Meters::new(1.5) * 2.0, Meters::new(3.0));
+        assert_eq!(Duration::new(1) + 2, Duration::new(3));
+        assert_eq!(Instant::new(5) - Instant::new(2), Duration::new(3));
+        assert!((Permissions::READ | Permissions::WRITE).contains(Permissions::WRITE));
+        assert_eq!(!Enabled::new(true), Enabled::new(false));
+        assert_eq!(Initial::default().value(), 'x');
+    }
+
+    #[test]
+    fn test_tagged() {
+        assert_eq!(Level::try_from(2), Ok(Level::High));
+        let error = "Medium".parse::<Level>().unwrap_err();
+        assert_eq!(error.type_name(), "Level");
+        assert_eq!(error.kind(), ErrorKind::UnknownVariant);
+    }
+
+    #[test]
+    fn test_nested_and_opaque() {
+        assert_eq!(Total::new(Counter::new(3)).primitive(), 3);
+        let timeout = Timeout::new(core::time::Duration::from_secs(1));
+        assert_eq!(timeout.value().as_secs(), 1);
+    }
+
+    #[test]
+    fn test_lifetime_types() {
+        assert_eq!(NameRef::from("alice").value(), "alice");
+        assert_eq!(Payload::new(b"\x01\x02").value(), &[1, 2]);
+    }
+}
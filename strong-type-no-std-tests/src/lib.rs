@@ -0,0 +1,81 @@
+//! Strong types declared in a `no_std` crate, to check that generated code only refers to `core`
+//! and to `alloc` through `strong_type`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+pub struct Counter(i32);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators = "minimal")]
+pub struct Small(u8);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators = "delegated", shift_rhs = u32)]
+pub struct Register(u64);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators = "minimal_delegated", scalable)]
+pub struct Meters(f64);
+
+#[derive(StrongType)]
+#[strong_type(addable, addable_primitive, conversion, inline = "always")]
+pub struct Duration(i64);
+
+#[derive(StrongType)]
+#[strong_type(point, delta = Duration)]
+pub struct Instant(i64);
+
+#[derive(StrongType)]
+#[strong_type(underlying = i32, auto_operators)]
+pub struct Offset(Counter);
+
+#[derive(StrongType)]
+#[strong_type(bitflags(READ = 1, WRITE = 2))]
+pub struct Permissions(u8);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+pub struct Enabled(bool);
+
+#[derive(StrongType)]
+pub struct Initial(char);
+
+#[derive(StrongType)]
+#[strong_type(tagged)]
+#[repr(u8)]
+pub enum Level {
+    Low = 1,
+    High = 2,
+}
+
+#[derive(StrongType)]
+#[strong_type(conversion, collectable, borrowed = NameStr)]
+pub struct Name(String);
+
+#[derive(StrongType)]
+#[strong_type(conversion, collectable)]
+pub struct Label(Cow<'static, str>);
+
+#[derive(StrongType)]
+#[strong_type(normalize = "lowercase", min_len = 1, max_len = 8, charset = "a-z")]
+pub struct Word(String);
+
+#[derive(StrongType)]
+#[strong_type(normalize = "trim")]
+pub struct Line(Cow<'static, str>);
+
+#[derive(StrongType)]
+#[strong_type(owned = Name, conversion)]
+pub struct NameRef<'a>(&'a str);
+
+#[derive(StrongType)]
+#[strong_type(owned = Name)]
+pub struct Text<'a>(Cow<'a, str>);
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use strong_type_no_std_tests::*;
+
+    #[test]
+    fn test_numeric_types() {
+        assert_eq!(Counter::new(2) * Counter::new(3), Counter::new(6));
+        assert_eq!(Small::new(2) + Small::new(3), Small::new(5));
+        assert_eq!(Register::new(1u64) << 4u32, Register::new(16u64));
+        assert_eq!(Meters::new(1.5) * 2.0, Meters::new(3.0));
+        assert_eq!(
+            [Duration::new(1), Duration::new(2)]
+                .into_iter()
+                .sum::<Duration>(),
+            Duration::new(3)
+        );
+        assert_eq!(Instant::new(5) - Instant::new(2), Duration::new(3));
+        assert_eq!(-Offset::new(Counter::new(1)), Offset::new(Counter::new(-1)));
+        assert!((Permissions::READ | Permissions::WRITE).contains(Permissions::WRITE));
+        assert_eq!(!Enabled::new(true), Enabled::new(false));
+        assert_eq!(Initial::new('a').value(), 'a');
+    }
+
+    #[test]
+    fn test_tagged() {
+        assert_eq!(Level::try_from(2), Ok(Level::High));
+        assert_eq!("Low".parse::<Level>(), Ok(Level::Low));
+        assert!(Level::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_string_types() {
+        let name = Name::from("alice");
+        let borrowed: &NameStr = &name;
+        assert_eq!(borrowed.to_owned(), name);
+        assert_eq!(
+            ["a", "b"].into_iter().collect::<Label>(),
+            Label::const_new("ab")
+        );
+        assert_eq!(Word::new("Word").value(), "word");
+        assert!(Word::try_new("two words").is_err());
+        assert_eq!(Line::new(" line ").value(), "line");
+    }
+
+    #[test]
+    fn test_lifetime_types() {
+        let name = NameRef::from("alice");
+        assert_eq!(name.value(), "alice");
+        assert_eq!(name.into_owned(), Name::new("alice"));
+        assert_eq!(Text::new("bob").into_owned(), Name::new("bob"));
+    }
+}
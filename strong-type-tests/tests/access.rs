@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+    use strong_type::StrongType;
+
+    mod account {
+        use strong_type::StrongType;
+
+        #[derive(StrongType)]
+        #[strong_type(constructor = "", accessor = "pub(super)", auto_operators)]
+        pub struct Balance(i64);
+
+        impl Balance {
+            pub fn open() -> Self {
+                Self::new(0)
+            }
+
+            pub fn deposit(&mut self, amount: i64) {
+                assert!(amount > 0);
+                *self += Self::new(amount);
+            }
+        }
+
+        #[derive(StrongType)]
+        #[strong_type(constructor = "pub(crate)", min_len = 1)]
+        pub struct Owner(String);
+
+        #[derive(StrongType)]
+        #[strong_type(constructor = "pub(crate)", addable, scalable)]
+        pub struct Fee(i64);
+    }
+
+    use account::{Balance, Fee, Owner};
+
+    #[test]
+    fn test_restricted_constructor() {
+        let mut balance = Balance::open();
+        balance.deposit(25);
+        assert_eq!(balance.value(), 25);
+        assert_eq!(*balance.as_ref(), 25);
+        assert_eq!(balance.into_inner(), 25);
+
+        // Trait impls would bypass the restricted accessors
+        assert_not_impl_any!(Balance: AsRef<i64>, AsMut<i64>);
+
+        assert_eq!(Owner::try_new("alice").unwrap().value(), "alice");
+    }
+
+    #[test]
+    fn test_restricted_constructor_operators() {
+        // from_primitive would construct values from any primitive
+        assert_not_impl_any!(Fee: strong_type::delegation::StrongTypeOps);
+
+        let fee = Fee::new(3) + Fee::new(4);
+        assert_eq!(fee * 2, Fee::new(14));
+    }
+
+    #[test]
+    fn test_immutable() {
+        #[derive(StrongType)]
+        #[strong_type(immutable, conversion, normalize = "lowercase")]
+        struct Hostname(String);
+
+        assert_impl_all!(Hostname: AsRef<String>, From<String>);
+        assert_not_impl_any!(Hostname: AsMut<String>);
+        assert_not_impl_any!(&'static mut Hostname: Into<&'static mut String>);
+
+        let host = Hostname::new("Example.org");
+        assert_eq!(host.as_ref(), "example.org");
+        assert_eq!(String::from(host), "example.org");
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use strong_type::StrongType;
+
+    #[test]
+    fn test_addable_primitive() {
+        #[derive(StrongType)]
+        #[strong_type(addable_primitive)]
+        struct Price(f64);
+
+        let price = Price::new(10.0);
+        assert_eq!(price + 1.5, Price::new(11.5));
+        assert_eq!(price - 1.5, Price::new(8.5));
+        assert_eq!(1.5 + price, Price::new(11.5));
+        assert_eq!(&price + 1.5, Price::new(11.5));
+        assert_eq!(&price - 1.5, Price::new(8.5));
+        assert_eq!(1.5 + &price, Price::new(11.5));
+
+        let mut price = price;
+        price += 2.0;
+        assert_eq!(price, Price::new(12.0));
+        price -= 4.0;
+        assert_eq!(price, Price::new(8.0));
+    }
+
+    #[test]
+    fn test_addable_primitive_with_operators() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal", addable_primitive)]
+        struct Minimal(i32);
+
+        let mut x = Minimal::new(1) + 2;
+        x -= 1;
+        assert_eq!(3 + x, Minimal::new(5));
+        assert_eq!(x + Minimal::new(1), Minimal::new(3));
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "delegated", addable_primitive)]
+        struct Delegated(u32);
+
+        let y = Delegated::new(5u32);
+        assert_eq!(y - 2, Delegated::new(3u32));
+        assert_eq!(&y + 2, Delegated::new(7u32));
+        assert_eq!(2 + y, Delegated::new(7u32));
+        assert_eq!(y + y, Delegated::new(10u32));
+    }
+}
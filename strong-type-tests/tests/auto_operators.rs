@@ -223,6 +223,41 @@ mod tests {
         assert_eq!(x_ref >> shift, CacheSize::new(1));
     }
 
+    #[test]
+    fn test_bit_shift_rhs() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators, shift_rhs = u32)]
+        struct CacheSize(u64);
+
+        let mut x = CacheSize::new(5u64);
+        assert_eq!(x << 2, CacheSize::new(20u64));
+        assert_eq!(x >> 2, CacheSize::new(1u64));
+        x <<= 3;
+        assert_eq!(x, CacheSize::new(40u64));
+    }
+
+    #[test]
+    fn test_checked_shift() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators)]
+        struct CacheSize(u8);
+
+        let x = CacheSize::new(0b1001u8);
+        assert_eq!(x.checked_shl(4), Some(CacheSize::new(0b1001_0000u8)));
+        assert_eq!(x.checked_shl(8), None);
+        assert_eq!(x.checked_shr(3), Some(CacheSize::new(1u8)));
+        assert_eq!(x.checked_shr(8), None);
+        assert_eq!(x.wrapping_shl(9), CacheSize::new(0b1_0010u8));
+        assert_eq!(x.wrapping_shr(11), CacheSize::new(1u8));
+
+        #[derive(StrongType)]
+        #[strong_type(ops(shl))]
+        struct Offset(i32);
+
+        const SHIFTED: Option<Offset> = Offset::const_new(-1).checked_shl(31);
+        assert_eq!(SHIFTED, Some(Offset::MIN));
+    }
+
     #[test]
     fn test_bit_logical() {
         #[derive(StrongType)]
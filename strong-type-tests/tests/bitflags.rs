@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use strong_type::StrongType;
+
+    #[derive(StrongType)]
+    #[strong_type(bitflags(READ = 0b001, WRITE = 0b010, EXECUTE = 0b100))]
+    struct Permissions(u32);
+
+    #[test]
+    fn test_flag_constants_and_operators() {
+        let read_write = Permissions::READ | Permissions::WRITE;
+        assert_eq!(read_write, Permissions::new(0b011u32));
+        assert_eq!(read_write & Permissions::WRITE, Permissions::WRITE);
+        assert_eq!(read_write ^ Permissions::READ, Permissions::WRITE);
+        assert_eq!(!Permissions::ZERO, Permissions::MAX);
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(&read_write & Permissions::READ, Permissions::READ);
+            assert_eq!(
+                Permissions::READ | &Permissions::EXECUTE,
+                Permissions::new(0b101u32)
+            );
+        }
+
+        let mut flags = Permissions::READ;
+        flags |= Permissions::EXECUTE;
+        flags &= !Permissions::READ;
+        assert_eq!(flags, Permissions::EXECUTE);
+    }
+
+    #[test]
+    fn test_flag_helpers() {
+        let mut flags = Permissions::READ | Permissions::WRITE;
+        assert!(flags.contains(Permissions::READ));
+        assert!(!flags.contains(Permissions::READ | Permissions::EXECUTE));
+        assert!(flags.intersects(Permissions::READ | Permissions::EXECUTE));
+        assert!(!flags.intersects(Permissions::EXECUTE));
+
+        flags.insert(Permissions::EXECUTE);
+        assert_eq!(flags, Permissions::new(0b111u32));
+        flags.remove(Permissions::READ | Permissions::WRITE);
+        assert_eq!(flags, Permissions::EXECUTE);
+        flags.toggle(Permissions::EXECUTE | Permissions::READ);
+        assert_eq!(flags, Permissions::READ);
+        flags.remove(Permissions::READ);
+        assert!(flags.is_empty());
+
+        const READ_WRITE: Permissions = Permissions(0b011);
+        let can_write = const { READ_WRITE.contains(Permissions::WRITE) };
+        assert!(can_write);
+    }
+
+    #[test]
+    fn test_bitflags_with_modes() {
+        #[derive(StrongType)]
+        #[strong_type(bitflags)]
+        struct Mask(i64);
+
+        assert_eq!(Mask::new(0b1100) & Mask::new(0b0110), Mask::new(0b0100));
+        assert_eq!(!Mask::ZERO, Mask::new(-1));
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal", bitflags(LOW = 0x0f, HIGH = 0xf0))]
+        struct Nibbles(u8);
+
+        assert_eq!(Nibbles::LOW | Nibbles::HIGH, Nibbles::MAX);
+        assert_eq!(Nibbles::LOW + Nibbles::LOW, Nibbles::new(0x1eu8));
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "delegated", bitflags(A = 1, B = 2))]
+        struct Delegated(u16);
+
+        assert_eq!(!Delegated::A & Delegated::new(3u16), Delegated::B);
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(&Delegated::A | &Delegated::B, Delegated::new(3u16));
+        }
+    }
+}
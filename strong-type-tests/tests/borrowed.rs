@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use std::borrow::{Borrow, Cow};
+    use std::collections::{BTreeSet, HashMap};
+    use strong_type::StrongType;
+
+    #[derive(StrongType)]
+    #[strong_type(borrowed = TagStr)]
+    struct Tag(String);
+
+    fn read_only(tag: &TagStr) -> usize {
+        tag.value().len()
+    }
+
+    #[test]
+    fn test_borrowed_companion() {
+        let tag = Tag::new("dev");
+
+        assert_eq!(read_only(&tag), 3);
+        assert_eq!(read_only(TagStr::new("prod")), 4);
+
+        let borrowed: &TagStr = tag.borrow();
+        assert_eq!(borrowed, TagStr::new("dev"));
+        assert_eq!(tag, *borrowed);
+        assert_eq!(tag, borrowed);
+        assert_eq!(*borrowed, tag);
+        assert_eq!(borrowed.to_owned(), tag);
+        assert_eq!(Tag::from(borrowed), tag);
+
+        assert_eq!(borrowed.to_string(), "TagStr(dev)");
+        assert_eq!(format!("{:?}", borrowed), "TagStr { value: \"dev\" }");
+    }
+
+    #[test]
+    fn test_borrowed_collection_lookup() {
+        let mut map = HashMap::new();
+        map.insert(Tag::new("dev"), 1);
+        map.insert(Tag::new("prod"), 2);
+        assert_eq!(map.get(TagStr::new("prod")), Some(&2));
+        assert_eq!(map.get(TagStr::new("test")), None);
+
+        let set: BTreeSet<Tag> = ["b", "a", "c"].into_iter().map(Tag::new).collect();
+        assert!(set.contains(TagStr::new("a")));
+        assert!(TagStr::new("a") < TagStr::new("b"));
+    }
+
+    #[test]
+    fn test_borrowed_const() {
+        #[derive(StrongType)]
+        #[strong_type(borrowed = EnvStr)]
+        struct Env(Cow<'static, str>);
+
+        const PROD: &EnvStr = EnvStr::new("prod");
+
+        assert_eq!(Env::const_new("prod"), PROD);
+        assert_eq!(PROD.to_owned(), Env::new(String::from("prod")));
+    }
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use strong_type::StrongType;
+
+    #[test]
+    fn test_string_collection() {
+        #[derive(StrongType)]
+        #[strong_type(collectable)]
+        struct Path(String);
+
+        let path: Path = ["usr", "/", "local"].into_iter().collect();
+        assert_eq!(path, Path::new("usr/local"));
+
+        let reversed: Path = "abc".chars().rev().collect();
+        assert_eq!(reversed, Path::new("cba"));
+
+        let joined: Path = [Path::new("a"), Path::new("b")].into_iter().collect();
+        assert_eq!(joined, Path::new("ab"));
+
+        let mut path = Path::new("usr");
+        path.extend(["/", "bin"]);
+        path.extend(['/', 'x']);
+        path.extend([String::from("y")]);
+        assert_eq!(path, Path::new("usr/bin/xy"));
+    }
+
+    #[test]
+    fn test_static_str_collection() {
+        #[derive(StrongType)]
+        #[strong_type(collectable)]
+        struct Segment(Cow<'static, str>);
+
+        let mut segment = Segment::const_new("usr");
+        segment.extend(["/", "lib"]);
+        assert_eq!(segment, Segment::new("usr/lib"));
+
+        let collected: Segment = "xyz".chars().collect();
+        assert_eq!(collected.value(), "xyz");
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use static_assertions::assert_not_impl_any;
+    use std::borrow::Cow;
+    use std::str::FromStr;
+    use strong_type::{Error, ErrorKind, StrongType};
+
+    #[derive(StrongType)]
+    #[strong_type(min_len = 3, max_len = 16, charset = "a-z0-9-")]
+    struct Slug(String);
+
+    #[test]
+    fn test_length_and_charset() {
+        assert_eq!(Slug::try_new("hello-world"), Ok(Slug::new("hello-world")));
+        assert!(Slug::validate("abc").is_ok());
+        assert!(Slug::validate("0123456789abcdef").is_ok());
+
+        let error = Slug::try_new("ab").unwrap_err();
+        assert_eq!(error.type_name(), "Slug");
+        assert_eq!(error.value(), "ab");
+        assert_eq!(error.kind(), ErrorKind::Constraint);
+        assert_eq!(error.reason(), "must be at least 3 characters long");
+        assert_eq!(
+            error.to_string(),
+            "invalid Slug 'ab': must be at least 3 characters long"
+        );
+
+        let error = Slug::try_new("a".repeat(17)).unwrap_err();
+        assert_eq!(error.reason(), "must be at most 16 characters long");
+
+        let error = Slug::try_new("Hello").unwrap_err();
+        assert_eq!(
+            error.reason(),
+            "contains 'H', which is not in the charset 'a-z0-9-'"
+        );
+    }
+
+    #[test]
+    fn test_length_counts_characters() {
+        #[derive(StrongType)]
+        #[strong_type(max_len = 2)]
+        struct Initials(String);
+
+        assert!(Initials::try_new("ÅÖ").is_ok());
+        assert!(Initials::try_new("ÅÖÜ").is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Slug::from_str("rust-1"), Ok(Slug::new("rust-1")));
+        assert_eq!(
+            "no spaces".parse::<Slug>(),
+            Err(Error::constraint(
+                "Slug",
+                "no spaces",
+                "contains ' ', which is not in the charset 'a-z0-9-'"
+            ))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Slug 'x': must be at least 3 characters long")]
+    fn test_new_panics_on_invalid_value() {
+        let _ = Slug::new("x");
+    }
+
+    #[test]
+    fn test_pattern() {
+        #[derive(StrongType)]
+        #[strong_type(pattern = r"[A-Z]{2}-\d{4}")]
+        struct Ticket(Cow<'static, str>);
+
+        assert_eq!(Ticket::new("AB-1234").value(), "AB-1234");
+        // Patterns must match the whole value
+        assert!(Ticket::try_new("xAB-1234").is_err());
+        assert!(Ticket::try_new("AB-12345").is_err());
+
+        let error = Ticket::try_new("ab-1234").unwrap_err();
+        assert_eq!(
+            error.reason(),
+            r"does not match the pattern '[A-Z]{2}-\d{4}'"
+        );
+    }
+
+    #[test]
+    fn test_escaped_charset() {
+        #[derive(StrongType)]
+        #[strong_type(charset = r"0-9a-f\-")]
+        struct HexId(String);
+
+        assert!(HexId::try_new("00-ff").is_ok());
+        assert!(HexId::try_new("00_ff").is_err());
+    }
+
+    #[test]
+    fn test_normalized_before_validation() {
+        #[derive(StrongType)]
+        #[strong_type(normalize = "trim", min_len = 1, charset = "a-z")]
+        struct Word(String);
+
+        assert_eq!(Word::try_new("  word "), Ok(Word::new("word")));
+        assert_eq!(" word".parse::<Word>(), Ok(Word::new("word")));
+        assert!(Word::try_new("   ").is_err());
+    }
+
+    #[test]
+    fn test_conversion_is_fallible() {
+        #[derive(StrongType)]
+        #[strong_type(conversion, min_len = 3, charset = "a-z")]
+        struct Handle(String);
+
+        #[derive(StrongType)]
+        #[strong_type(conversion, max_len = 4)]
+        struct Code(Cow<'static, str>);
+
+        assert_eq!(Handle::try_from("abc"), Ok(Handle::new("abc")));
+        assert_eq!(
+            Handle::try_from(String::from("abc")),
+            Ok(Handle::new("abc"))
+        );
+        assert_eq!(
+            Handle::try_from(&String::from("abc")),
+            Ok(Handle::new("abc"))
+        );
+        assert_eq!(Handle::try_from("ab").unwrap_err().value(), "ab");
+        assert!(Handle::try_from("ABC").is_err());
+        assert_eq!(String::from(Handle::new("abc")), "abc");
+
+        assert_eq!(Code::try_from("ab12"), Ok(Code::new("ab12")));
+        assert!(Code::try_from(String::from("abcde")).is_err());
+        assert!(Code::try_from(Cow::Borrowed("abcde")).is_err());
+
+        assert_not_impl_any!(Handle: From<String>, From<&'static str>);
+        assert_not_impl_any!(Code: From<String>, From<&'static str>);
+    }
+}
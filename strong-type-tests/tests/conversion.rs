@@ -30,4 +30,19 @@ mod tests {
         test(value.clone(), string_value.clone().into());
         test(value.into(), string_value);
     }
+
+    #[test]
+    fn test_static_str_conversion() {
+        use std::borrow::Cow;
+
+        #[derive(StrongType)]
+        #[strong_type(conversion)]
+        struct NamedCow(Cow<'static, str>);
+
+        const VALUE: NamedCow = NamedCow::const_new("test");
+
+        test(VALUE, "test".into());
+        test(NamedCow::const_new("test"), String::from("test").into());
+        test(VALUE.into(), Cow::Borrowed("test"));
+    }
 }
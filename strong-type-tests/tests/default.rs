@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use strong_type::StrongType;
+
+    #[test]
+    fn test_primitive_default() {
+        #[derive(StrongType)]
+        #[strong_type(default = 8080)]
+        struct Port(u16);
+
+        #[derive(StrongType)]
+        #[strong_type(default = 30_000)]
+        struct Timeout(u64);
+
+        #[derive(StrongType)]
+        #[strong_type(default = 0.5)]
+        struct Ratio(f64);
+
+        #[derive(StrongType)]
+        #[strong_type(default = true)]
+        struct Enabled(bool);
+
+        assert_eq!(Port::default(), Port::new(8080u16));
+        assert_eq!(Timeout::default().value(), 30_000);
+        assert_eq!(Ratio::default(), Ratio::new(0.5));
+        assert!(Enabled::default().value());
+
+        const PORT: Port = Port::DEFAULT;
+        assert_eq!(PORT.value(), 8080);
+    }
+
+    #[test]
+    fn test_const_expression_default() {
+        const BASE: i32 = 40;
+
+        #[derive(StrongType)]
+        #[strong_type(default = BASE + 2)]
+        struct Answer(i32);
+
+        assert_eq!(Answer::DEFAULT, Answer::new(42));
+    }
+
+    #[test]
+    fn test_string_default() {
+        #[derive(StrongType)]
+        #[strong_type(default = "localhost", normalize = "lowercase")]
+        struct Hostname(String);
+
+        #[derive(StrongType)]
+        #[strong_type(default = "guest", min_len = 3, charset = "a-z")]
+        struct Username(Cow<'static, str>);
+
+        assert_eq!(Hostname::default().value(), "localhost");
+        assert_eq!(Username::default(), Username::new("guest"));
+    }
+
+    #[test]
+    fn test_nested_default() {
+        #[derive(StrongType)]
+        struct Cents(i64);
+
+        #[derive(StrongType)]
+        #[strong_type(underlying = i64, default = Cents::new(100))]
+        struct Price(Cents);
+
+        assert_eq!(Price::default().primitive(), 100);
+    }
+}
@@ -148,6 +148,57 @@ mod tests {
         // Test Product
         let product: Amount = values.iter().copied().product();
         assert_eq!(product, Amount::new(6));
+
+        // Borrowed items
+        assert_eq!(values.iter().sum::<Amount>(), Amount::new(6));
+        assert_eq!(values.iter().product::<Amount>(), Amount::new(6));
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "delegated")]
+        struct Price(f64);
+
+        let prices = [Price::new(1.5), Price::new(2.5)];
+        assert_eq!(prices.iter().sum::<Price>(), Price::new(4.0));
+        assert_eq!(std::iter::empty::<Price>().sum::<Price>(), Price::ZERO);
+        assert_eq!(std::iter::empty::<Price>().product::<Price>(), Price::ONE);
+    }
+
+    #[test]
+    fn test_minimal_delegated() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal_delegated")]
+        struct Distance(i32);
+
+        let mut a = Distance::new(10);
+        let b = Distance::new(4);
+
+        assert_eq!(a + b, Distance::new(14));
+        assert_eq!(a - b, Distance::new(6));
+        assert_eq!(a * b, Distance::new(40));
+        assert_eq!(a / b, Distance::new(2));
+        assert_eq!(a % b, Distance::new(2));
+        assert_eq!(-a, Distance::new(-10));
+
+        a += b;
+        a *= Distance::new(2);
+        assert_eq!(a, Distance::new(28));
+
+        let values = [Distance::new(2), Distance::new(3)];
+        assert_eq!(values.iter().sum::<Distance>(), Distance::new(5));
+        assert_eq!(values.into_iter().product::<Distance>(), Distance::new(6));
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal_delegated", scalable)]
+        struct Ratio(f32);
+
+        assert_eq!(Ratio::new(1.5) * 2.0, Ratio::new(3.0));
+        assert_eq!(-Ratio::new(1.5), Ratio::new(-1.5));
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal_delegated")]
+        struct IsSet(bool);
+
+        assert_eq!(!IsSet::new(true) | IsSet::new(true), IsSet::new(true));
     }
 
     #[test]
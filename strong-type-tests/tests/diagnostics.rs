@@ -13,4 +13,94 @@ mod tests {
         let t = TestCases::new();
         t.compile_fail("tests/ui/unsupported_underlying.rs");
     }
+
+    #[test]
+    fn test_adding_two_points_does_not_compile() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/point_addition.rs");
+    }
+
+    #[test]
+    fn test_unsupported_operator_is_reported() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/unsupported_operator.rs");
+    }
+
+    #[test]
+    fn test_shift_rhs_restricts_shift_operand() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/restricted_shift_rhs.rs");
+    }
+
+    #[test]
+    fn test_tagged_enum_requires_fieldless_variants() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/tagged_enum_with_fields.rs");
+    }
+
+    #[test]
+    fn test_invalid_constraints_are_reported() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/invalid_constraints.rs");
+    }
+
+    #[test]
+    fn test_invalid_skip_is_reported() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/invalid_skip.rs");
+    }
+
+    #[test]
+    fn test_restricted_accessors_are_private() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/restricted_access.rs");
+    }
+
+    #[test]
+    fn test_invalid_default_is_reported() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/invalid_default.rs");
+    }
+
+    #[test]
+    fn test_nested_operators_require_the_inner_operators() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/nested_operators.rs");
+    }
+
+    #[test]
+    fn test_invalid_primitive_is_reported() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/invalid_primitive.rs");
+    }
+
+    #[test]
+    fn test_conflicting_attributes_are_reported() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/conflicting_attributes.rs");
+    }
+
+    #[test]
+    fn test_invalid_lifetime_types_are_reported() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/invalid_lifetime.rs");
+    }
+
+    #[test]
+    fn test_invalid_opaque_is_reported() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/invalid_opaque.rs");
+    }
+
+    #[test]
+    fn test_unknown_field_type_suggests_opaque() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/not_a_strong_type.rs");
+    }
+
+    #[test]
+    fn test_normalized_or_validated_values_cannot_be_mutated() {
+        let t = TestCases::new();
+        t.compile_fail("tests/ui/invariant_mutation.rs");
+    }
 }
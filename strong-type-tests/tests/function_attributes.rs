@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::panic::{self, Location};
+    use strong_type::StrongType;
+
+    thread_local! {
+        static PANIC_LINE: Cell<Option<u32>> = const { Cell::new(None) };
+    }
+
+    /// Runs `f`, which must panic, and returns the line the panic was reported at
+    fn panic_line<R>(f: impl FnOnce() -> R + panic::UnwindSafe) -> u32 {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(|info| {
+            PANIC_LINE.set(info.location().map(Location::line));
+        }));
+        let result = panic::catch_unwind(f);
+        panic::set_hook(previous_hook);
+
+        assert!(result.is_err());
+        PANIC_LINE.take().unwrap()
+    }
+
+    /// Asserts that evaluating the expression panics on the line of the assertion
+    macro_rules! assert_panics_here {
+        ($expression:expr) => {
+            assert_eq!(panic_line(move || $expression), line!())
+        };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_overflow_panics_at_caller() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators)]
+        struct Full(u8);
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal")]
+        struct Minimal(i32);
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "delegated", scalable)]
+        struct Delegated(u16);
+
+        assert_panics_here!(Full::MAX + Full::ONE);
+        #[allow(clippy::op_ref)]
+        {
+            assert_panics_here!(&Full::MIN - Full::ONE);
+        }
+        assert_panics_here!(-Minimal::MIN);
+        assert_panics_here!(Delegated::MAX * Delegated::new(2u16));
+        assert_panics_here!(Delegated::MAX * 2);
+    }
+
+    #[test]
+    fn test_division_by_zero_panics_at_caller() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators)]
+        struct Count(i64);
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal_delegated")]
+        struct Compact(u32);
+
+        assert_panics_here!(Count::ONE / Count::ZERO);
+        assert_panics_here!(Compact::ONE % Compact::ZERO);
+        let mut count = Count::ONE;
+        assert_panics_here!(count /= Count::ZERO);
+    }
+
+    #[test]
+    fn test_float_clamp_panics_at_caller() {
+        #[derive(StrongType)]
+        struct Meter(f64);
+
+        assert_panics_here!(Meter::new(1.0).clamp(Meter::new(3.0), Meter::new(2.0)));
+        assert_panics_here!(Meter::new(1.0).clamp(Meter::NAN, Meter::new(2.0)));
+    }
+
+    #[test]
+    fn test_inline_modes() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators, inline = "always")]
+        struct Always(i32);
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "delegated", inline = "never")]
+        struct Never(f64);
+
+        #[derive(StrongType)]
+        #[strong_type(inline = "default", borrowed = NameStr)]
+        struct Name(String);
+
+        assert_eq!(Always::new(1) + Always::new(2), Always::new(3));
+        assert_eq!(Never::new(1.5) * Never::new(2.0), Never::new(3.0));
+        assert_eq!(Name::new("tag"), NameStr::new("tag"));
+    }
+}
@@ -0,0 +1,134 @@
+// Strong types declared without the standard prelude, to check that generated code only uses
+// fully-qualified paths.
+#[cfg(test)]
+#[allow(dead_code)]
+#[no_implicit_prelude]
+mod tests {
+    use ::std::borrow::Cow;
+    use ::std::string::String;
+    use ::strong_type::StrongType;
+
+    #[derive(StrongType)]
+    #[strong_type(auto_operators)]
+    struct Counter(i32);
+
+    #[derive(StrongType)]
+    #[strong_type(auto_operators = "minimal_delegated", ops(shl), shift_rhs = u32)]
+    struct Register(u64);
+
+    #[derive(StrongType)]
+    #[strong_type(auto_operators = "delegated", scalable, addable_primitive, conversion)]
+    struct Meters(f64);
+
+    #[derive(StrongType)]
+    #[strong_type(addable, inline = "never")]
+    struct Delta(i64);
+
+    #[derive(StrongType)]
+    #[strong_type(point, delta = Delta)]
+    struct Timestamp(i64);
+
+    #[derive(StrongType)]
+    #[strong_type(underlying = i32, auto_operators)]
+    struct Offset(Counter);
+
+    #[derive(StrongType)]
+    #[strong_type(bitflags(READ = 1, WRITE = 2))]
+    struct Permissions(u8);
+
+    #[derive(StrongType)]
+    #[strong_type(auto_operators)]
+    struct Enabled(bool);
+
+    #[derive(StrongType)]
+    struct Initial(char);
+
+    #[derive(StrongType)]
+    #[strong_type(tagged)]
+    #[repr(u8)]
+    enum Level {
+        Low = 1,
+        High = 2,
+    }
+
+    #[derive(StrongType)]
+    #[strong_type(conversion, collectable, borrowed = NameStr)]
+    struct Name(String);
+
+    #[derive(StrongType)]
+    #[strong_type(underlying = String)]
+    struct Nickname(Name);
+
+    #[derive(StrongType)]
+    #[strong_type(conversion, collectable)]
+    struct Label(Cow<'static, str>);
+
+    #[derive(StrongType)]
+    #[strong_type(
+        normalize = "lowercase",
+        min_len = 1,
+        charset = "a-z",
+        pattern = "[a-z]+"
+    )]
+    struct Word(String);
+
+    #[derive(StrongType)]
+    #[strong_type(normalize = "trim")]
+    struct Line(Cow<'static, str>);
+
+    #[derive(StrongType)]
+    struct Total(Counter);
+
+    #[derive(StrongType)]
+    #[strong_type(opaque)]
+    struct Timeout(::std::time::Duration);
+
+    #[derive(StrongType)]
+    #[strong_type(owned = Name, conversion)]
+    struct NameRef<'a>(&'a str);
+
+    #[derive(StrongType)]
+    struct Payload<'a>(&'a [u8]);
+
+    #[derive(StrongType)]
+    #[strong_type(owned = Name)]
+    struct Text<'a>(Cow<'a, str>);
+
+    #[derive(StrongType)]
+    #[strong_type(default = 8080)]
+    struct Port(u16);
+
+    #[derive(StrongType)]
+    #[strong_type(default = "guest", min_len = 1)]
+    struct User(String);
+
+    mod facade {
+        pub use ::strong_type;
+    }
+
+    mod strong_type {
+        pub const PORT: u16 = 8080;
+    }
+
+    // The crate path only applies to generated paths, not to a `strong_type` module in user input
+    #[derive(StrongType)]
+    #[strong_type(crate = "self::facade::strong_type", default = self::strong_type::PORT)]
+    struct ListenPort(u16);
+
+    #[test]
+    fn test_without_prelude() {
+        ::std::assert_eq!(Counter::new(2) + Counter::new(3), Counter::new(5));
+        ::std::assert_eq!(Word::new("Word").value(), "word");
+        ::std::assert_eq!(Total::new(Counter::new(1)).primitive(), 1);
+        ::std::assert_eq!(NameRef::new("ada").into_owned(), Name::new("ada"));
+        ::std::assert_eq!(<Port as ::std::default::Default>::default().value(), 8080);
+        ::std::assert_eq!(
+            <User as ::std::default::Default>::default().value(),
+            "guest"
+        );
+        ::std::assert_eq!(
+            <ListenPort as ::std::default::Default>::default().value(),
+            8080
+        );
+    }
+}
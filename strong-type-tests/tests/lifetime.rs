@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+    use std::borrow::Cow;
+    use std::collections::HashSet;
+    use std::fmt::Display;
+    use std::hash::Hash;
+    use strong_type::{StrongType, StrongTypeAccess};
+
+    #[derive(StrongType)]
+    struct UserName(String);
+
+    #[derive(StrongType)]
+    #[strong_type(owned = UserName, conversion)]
+    struct UserNameRef<'a>(&'a str);
+
+    #[derive(StrongType)]
+    struct Payload<'a>(&'a [u8]);
+
+    #[derive(StrongType)]
+    #[strong_type(owned = UserName, conversion)]
+    struct Label<'a>(Cow<'a, str>);
+
+    /// Returns the first word, borrowing from the input rather than from the strong type
+    fn first_word(input: &str) -> UserNameRef<'_> {
+        UserNameRef::new(input.split(' ').next().unwrap_or_default())
+    }
+
+    #[test]
+    fn test_str() {
+        let input = String::from("alice bob");
+        let value = first_word(&input).value();
+        assert_eq!(value, "alice");
+
+        let name = UserNameRef::new("alice");
+        assert_eq!(name.primitive(), "alice");
+        assert_eq!(name.into_inner(), "alice");
+        assert_eq!(name.to_string(), "UserNameRef(alice)");
+        assert_eq!(format!("{:?}", name), "UserNameRef { value: \"alice\" }");
+        assert_eq!(UserNameRef::default().value(), "");
+        assert!(UserNameRef::new("a") < UserNameRef::new("b"));
+        assert_eq!(name.as_value(), name);
+
+        let names = HashSet::from([name, UserNameRef::new("bob")]);
+        assert!(names.contains(&UserNameRef::new("alice")));
+        assert_impl_all!(UserNameRef<'static>: Copy, Eq, Ord, Hash, Display, Default, AsRef<str>);
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let owned = {
+            let input = String::from("carol");
+            UserNameRef::new(&input).into_owned()
+        };
+        assert_eq!(owned, UserName::new("carol"));
+        assert_eq!(Label::new("dave").into_owned(), UserName::new("dave"));
+    }
+
+    #[test]
+    fn test_const_constructor() {
+        const ADMIN: UserNameRef<'static> = UserNameRef::new("admin");
+        assert_eq!(ADMIN.value(), "admin");
+    }
+
+    #[test]
+    fn test_bytes() {
+        let payload = Payload::new(b"\x01\x02");
+        assert_eq!(payload.value(), &[1, 2]);
+        assert_eq!(payload.as_ref(), &[1u8, 2]);
+        assert!(Payload::new(b"\x01") < payload);
+        assert_impl_all!(Payload<'static>: Copy, Eq, Ord, Hash, Default, AsRef<[u8]>);
+        assert_not_impl_any!(Payload<'static>: Display);
+    }
+
+    #[test]
+    fn test_cow() {
+        let borrowed = Label::new("static");
+        let owned = Label::new(String::from("owned"));
+        assert_eq!(borrowed.value(), "static");
+        assert_eq!(owned.primitive(), "owned");
+        assert!(matches!(borrowed.into_inner(), Cow::Borrowed("static")));
+        assert_eq!(owned.to_string(), "Label(owned)");
+        assert_eq!(Label::from("x"), Label::new(Cow::Borrowed("x")));
+        assert_impl_all!(Label<'static>: Clone, Eq, Ord, Hash, Display, Default);
+        assert_not_impl_any!(Label<'static>: Copy);
+    }
+
+    #[test]
+    fn test_conversion() {
+        let name: UserNameRef = "erin".into();
+        let value: &str = name.into();
+        assert_eq!(value, "erin");
+    }
+
+    #[test]
+    fn test_skip() {
+        #[derive(StrongType)]
+        #[strong_type(skip(Default), custom_display)]
+        struct Token<'t>(&'t str);
+
+        impl Display for Token<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("***")
+            }
+        }
+
+        assert_eq!(Token::new("secret").to_string(), "***");
+        assert_not_impl_any!(Token<'static>: Default);
+    }
+}
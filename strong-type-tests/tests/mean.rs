@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+    use std::ops::{Div, DivAssign};
+    use strong_type::StrongType;
+
+    #[test]
+    fn test_float_mean() {
+        #[derive(StrongType)]
+        #[strong_type(addable)]
+        struct Latency(f64);
+
+        let samples = [Latency::new(1.0), Latency::new(2.0), Latency::new(6.0)];
+        assert_eq!(Latency::mean(samples), Some(Latency::new(3.0)));
+        assert_eq!(Latency::mean(samples.iter()), Some(Latency::new(3.0)));
+        assert_eq!(Latency::mean(Vec::<Latency>::new()), None);
+
+        let total: Latency = samples.iter().sum();
+        assert_eq!(total / samples.len(), Latency::new(3.0));
+        assert_eq!(&total / 3, Latency::new(3.0));
+
+        let mut total = total;
+        total /= 9;
+        assert_eq!(total, Latency::new(1.0));
+    }
+
+    #[test]
+    fn test_int_mean() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal")]
+        struct Offset(i8);
+
+        // The sum of these values does not fit into an i8
+        let samples = vec![Offset::new(100); 200];
+        assert_eq!(Offset::mean(samples), Some(Offset::new(100)));
+        assert_eq!(
+            Offset::mean([Offset::new(-3), Offset::new(2)]),
+            Some(Offset::new(0))
+        );
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators)]
+        struct Count(u8);
+
+        assert_eq!(
+            Count::mean([Count::new(255), Count::new(255), Count::new(0)]),
+            Some(Count::new(170))
+        );
+        assert_eq!(Count::mean(Vec::<Count>::new()), None);
+    }
+
+    #[test]
+    fn test_128_bit_mean() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators)]
+        struct Balance(i128);
+
+        assert_eq!(
+            Balance::mean([Balance::new(i128::MIN), Balance::new(-2)]),
+            None
+        );
+        assert_eq!(
+            Balance::mean([Balance::new(i128::MAX), Balance::new(-1), Balance::new(-2)]),
+            Some(Balance::new((i128::MAX - 3) / 3))
+        );
+        assert_eq!(Balance::mean([Balance::new(i128::MAX); 2]), None);
+        assert_eq!(Balance::mean(Vec::<Balance>::new()), None);
+
+        #[derive(StrongType)]
+        #[strong_type(addable)]
+        struct Supply(u128);
+
+        assert_eq!(
+            Supply::mean([Supply::new(4u128), Supply::new(7u128)]),
+            Some(Supply::new(5u128))
+        );
+        assert_eq!(
+            Supply::mean([Supply::new(u128::MAX), Supply::new(1u128)]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_128_bit_alias_mean() {
+        type Raw = u128;
+
+        #[derive(StrongType)]
+        #[strong_type(primitive = u128, addable)]
+        struct Big(Raw);
+
+        assert_eq!(
+            Big::mean([Big::new(4u128), Big::new(7u128)]),
+            Some(Big::new(5u128))
+        );
+        assert_eq!(Big::mean([Big::new(u128::MAX), Big::new(u128::MAX)]), None);
+    }
+
+    #[test]
+    fn test_count_division_follows_operator_settings() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal")]
+        struct Minimal(f64);
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "delegated")]
+        struct Delegated(f64);
+
+        #[derive(StrongType)]
+        #[strong_type(ops(add, sub))]
+        struct Selected(f64);
+
+        #[derive(StrongType)]
+        #[strong_type(ops(div))]
+        struct Divided(f64);
+
+        assert_eq!(Minimal::new(6.0) / 3, Minimal::new(2.0));
+        assert_impl_all!(Minimal: Div<usize>, DivAssign<usize>);
+        assert_not_impl_any!(&'static Minimal: Div<usize>);
+
+        assert_eq!(Delegated::new(6.0) / 3, Delegated::new(2.0));
+        assert_eq!(&Delegated::new(6.0) / 2, Delegated::new(3.0));
+
+        assert_eq!(
+            Selected::mean([Selected::new(1.0)]),
+            Some(Selected::new(1.0))
+        );
+        assert_not_impl_any!(Selected: Div<usize>, DivAssign<usize>);
+
+        let mut divided = Divided::new(6.0);
+        divided /= 2;
+        assert_eq!(divided, Divided::new(3.0));
+    }
+}
@@ -38,6 +38,8 @@ mod tests {
         let arr = [Second(2), Second(3), Second(5)];
         assert_eq!(arr.into_iter().sum::<Second>(), Second(10));
         assert_eq!(arr.into_iter().product::<Second>(), Second(30));
+        assert_eq!(arr.iter().sum::<Second>(), Second(10));
+        assert_eq!(arr.iter().product::<Second>(), Second(30));
     }
 
     #[test]
@@ -107,6 +109,7 @@ mod tests {
         let arr = [Meter(2.0), Meter(3.0), Meter(5.0)];
         assert_eq!(arr.into_iter().sum::<Meter>(), Meter(10.0));
         assert_eq!(arr.into_iter().product::<Meter>(), Meter(30.0));
+        assert_eq!(arr.iter().sum::<Meter>(), Meter(10.0));
     }
 
     #[test]
@@ -0,0 +1,220 @@
+#[cfg(test)]
+mod tests {
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+    use std::collections::HashSet;
+    use std::fmt::Display;
+    use std::hash::Hash;
+    use strong_type::{StrongType, StrongTypeAccess};
+
+    #[derive(StrongType)]
+    #[strong_type(auto_operators)]
+    struct Dollar(i32);
+
+    #[derive(StrongType)]
+    #[strong_type(auto_operators)]
+    struct Cash(Dollar);
+
+    #[derive(StrongType)]
+    struct Coin(Cash);
+
+    #[derive(StrongType)]
+    struct Tag(String);
+
+    #[derive(StrongType)]
+    struct Label(Tag);
+
+    #[test]
+    fn test_nested_numeric() {
+        let cash = Cash::new(Dollar::new(10));
+        assert_eq!(cash.value(), Dollar::new(10));
+        assert_eq!(cash.primitive(), 10);
+        assert_eq!(cash.into_inner() + Dollar::new(5), Dollar::new(15));
+        assert_eq!(format!("{}", cash), "Cash(Dollar(10))");
+        assert_eq!(Cash::default(), Cash::new(Dollar::new(0)));
+        assert!(Cash::new(Dollar::new(1)) < Cash::new(Dollar::new(2)));
+        assert_eq!(size_of::<Cash>(), size_of::<i32>());
+
+        let primitive: <Cash as StrongType>::PrimitiveType = 1;
+        assert_eq!(primitive, 1i32);
+        assert_impl_all!(Cash: Copy, Eq, Ord, Hash, Display, Default);
+    }
+
+    #[test]
+    fn test_nested_several_levels() {
+        let coin = Coin::new(Cash::new(Dollar::new(10)));
+        assert_eq!(coin.value(), &Cash::new(Dollar::new(10)));
+        assert_eq!(coin.primitive(), 10);
+        assert_eq!(format!("{}", coin), "Coin(Cash(Dollar(10)))");
+        assert_eq!(coin.as_primitive(), 10);
+        assert_impl_all!(Coin: Copy, Eq, Ord, Hash, Display, Default);
+    }
+
+    #[test]
+    fn test_nested_string() {
+        let label = Label::new(Tag::new("dev"));
+        assert_eq!(label.value(), &Tag::new("dev"));
+        assert_eq!(label.primitive(), "dev");
+        assert_eq!(format!("{}", label), "Label(Tag(dev))");
+
+        let labels = HashSet::from([label.clone(), Label::new(Tag::new("ops"))]);
+        assert!(labels.contains(&label));
+        assert_not_impl_any!(Label: Copy);
+    }
+
+    #[test]
+    fn test_nested_tagged() {
+        #[derive(StrongType)]
+        #[strong_type(tagged)]
+        #[repr(u16)]
+        enum Status {
+            Ok = 200,
+            NotFound = 404,
+        }
+
+        #[derive(StrongType)]
+        struct Response(Status);
+
+        let response = Response::new(Status::NotFound);
+        assert_eq!(response.value(), Status::NotFound);
+        assert_eq!(response.primitive(), 404);
+        assert!(Response::new(Status::Ok) < response);
+        assert_impl_all!(Response: Copy, Eq, Ord, Hash);
+        assert_not_impl_any!(Response: Default);
+    }
+
+    #[test]
+    fn test_traits_follow_the_inner_type() {
+        #[derive(StrongType)]
+        struct Meters(f64);
+
+        #[derive(StrongType)]
+        struct Height(Meters);
+
+        #[derive(StrongType)]
+        #[strong_type(min_len = 1)]
+        struct Slug(String);
+
+        #[derive(StrongType)]
+        struct Path(Slug);
+
+        assert_eq!(Height::new(Meters::new(1.5)).primitive(), 1.5);
+        assert_impl_all!(Height: Copy, PartialOrd, Default);
+        assert_not_impl_any!(Height: Eq, Ord, Hash);
+
+        assert_eq!(Path::new(Slug::new("a")).primitive(), "a");
+        assert_impl_all!(Path: Eq, Ord, Hash);
+        assert_not_impl_any!(Path: Default, Copy);
+    }
+
+    #[test]
+    fn test_nested_attributes() {
+        #[derive(StrongType)]
+        #[strong_type(conversion, default = Dollar::new(7))]
+        struct Wallet(Dollar);
+
+        assert_eq!(Wallet::default().primitive(), 7);
+        assert_eq!(Wallet::from(Dollar::new(3)).primitive(), 3);
+        assert_eq!(Dollar::from(Wallet::new(Dollar::new(3))), Dollar::new(3));
+    }
+
+    #[test]
+    fn test_nested_operators() {
+        let a = Cash::new(Dollar::new(10));
+        let b = Cash::new(Dollar::new(4));
+        assert_eq!(a + b, Cash::new(Dollar::new(14)));
+        assert_eq!(a % b, Cash::new(Dollar::new(2)));
+        assert_eq!(-a, Cash::new(Dollar::new(-10)));
+        assert_eq!(a & b, Cash::new(Dollar::new(0)));
+        assert_eq!(a << 1u32, Cash::new(Dollar::new(20)));
+
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(a - &b, Cash::new(Dollar::new(6)));
+            assert_eq!(&a * b, Cash::new(Dollar::new(40)));
+            assert_eq!(&a / &b, Cash::new(Dollar::new(2)));
+            assert_eq!(-&a, Cash::new(Dollar::new(-10)));
+        }
+
+        let mut c = a;
+        c += b;
+        c -= &b;
+        c <<= 2u32;
+        assert_eq!(c, Cash::new(Dollar::new(40)));
+
+        let cash = [a, b];
+        assert_eq!(cash.iter().sum::<Cash>(), Cash::new(Dollar::new(14)));
+        assert_eq!(
+            cash.into_iter().product::<Cash>(),
+            Cash::new(Dollar::new(40))
+        );
+    }
+
+    #[test]
+    fn test_nested_operators_follow_the_inner_type() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators)]
+        struct Label(Tag);
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal")]
+        struct Pocket(Dollar);
+
+        assert_not_impl_any!(Label: std::ops::Add<Label>, std::ops::Neg<Output = Label>);
+        assert_impl_all!(Pocket: std::ops::Add<Pocket>, std::ops::AddAssign<Pocket>, std::ops::Neg<Output = Pocket>);
+        assert_not_impl_any!(Pocket: std::ops::Add<&'static Pocket>, std::ops::Shl<u32>);
+        assert_eq!(
+            Pocket::new(Dollar::new(1)) + Pocket::new(Dollar::new(2)),
+            Pocket::new(Dollar::new(3))
+        );
+    }
+
+    #[test]
+    fn test_nested_scalars() {
+        #[derive(StrongType)]
+        #[strong_type(addable, scalable, addable_primitive)]
+        struct Price(i64);
+
+        #[derive(StrongType)]
+        #[strong_type(addable, scalable, addable_primitive)]
+        struct Cost(Price);
+
+        let cost = Cost::new(Price::new(10));
+        assert_eq!(cost + cost, Cost::new(Price::new(20)));
+        assert_eq!(cost * 3, Cost::new(Price::new(30)));
+        assert_eq!(3 * cost, Cost::new(Price::new(30)));
+        assert_eq!(cost / 2, Cost::new(Price::new(5)));
+        assert_eq!(cost + 1, Cost::new(Price::new(11)));
+        assert_eq!(cost - 1, Cost::new(Price::new(9)));
+    }
+
+    #[test]
+    fn test_nested_point() {
+        #[derive(StrongType)]
+        #[strong_type(point, delta = Duration)]
+        struct Instant(i64);
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators)]
+        struct Duration(i64);
+
+        #[derive(StrongType)]
+        #[strong_type(point, delta = Duration)]
+        struct Deadline(Instant);
+
+        let start = Deadline::new(Instant::new(100));
+        let end = start + Duration::new(20);
+        assert_eq!(end, Deadline::new(Instant::new(120)));
+        assert_eq!(end - start, Duration::new(20));
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(&end - Duration::new(5), Deadline::new(Instant::new(115)));
+            assert_eq!(Duration::new(1) + &start, Deadline::new(Instant::new(101)));
+            assert_eq!(&end - &start, Duration::new(20));
+        }
+
+        let mut deadline = start;
+        deadline += Duration::new(5);
+        deadline -= Duration::new(1);
+        assert_eq!(deadline, Deadline::new(Instant::new(104)));
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+    use strong_type::StrongType;
+
+    #[test]
+    fn test_lowercase() {
+        #[derive(StrongType)]
+        #[strong_type(normalize = "lowercase", conversion)]
+        struct Hostname(String);
+
+        let host = Hostname::new("Example.COM");
+        assert_eq!(host.value(), "example.com");
+        assert_eq!(host, Hostname::new("EXAMPLE.com".to_string()));
+        assert_eq!(Hostname::from("ExAmPlE.cOm"), host);
+        assert_eq!(Hostname::from(String::from("EXAMPLE.COM")), host);
+        assert_eq!(Hostname::from_str("Example.Com"), Ok(host.clone()));
+
+        let mut hosts = HashSet::new();
+        hosts.insert(Hostname::new("a.org"));
+        hosts.insert(Hostname::new("A.ORG"));
+        assert_eq!(hosts.len(), 1);
+        assert!(hosts.contains(&"A.org".parse().unwrap()));
+        assert_eq!(Hostname::normalize("MiXeD"), "mixed");
+    }
+
+    #[test]
+    fn test_trim() {
+        #[derive(StrongType)]
+        #[strong_type(normalize = "trim")]
+        struct Sku(Cow<'static, str>);
+
+        let sku = Sku::new("  AB-123\n");
+        assert_eq!(sku.value(), "AB-123");
+        assert_eq!(sku, Sku::new(String::from("AB-123")));
+        assert_eq!(" AB-123 ".parse::<Sku>(), Ok(sku));
+    }
+
+    #[test]
+    fn test_custom_normalization() {
+        fn canonical_email(value: &str) -> String {
+            value.trim().to_ascii_lowercase()
+        }
+
+        #[derive(StrongType)]
+        #[strong_type(normalize = canonical_email)]
+        struct Email(String);
+
+        assert_eq!(
+            Email::new(" Alice@Example.org "),
+            Email::new("alice@example.org")
+        );
+
+        #[derive(StrongType)]
+        #[strong_type(normalize = str::to_ascii_uppercase)]
+        struct Code(String);
+
+        assert_eq!(Code::new("abc").value(), "ABC");
+        assert_eq!(Code::default().value(), "");
+    }
+}
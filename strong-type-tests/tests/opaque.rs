@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+    use std::collections::{BTreeSet, HashSet};
+    use std::fmt::{Debug, Display};
+    use std::hash::Hash;
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use strong_type::StrongType;
+
+    #[derive(StrongType)]
+    #[strong_type(opaque, conversion)]
+    struct Address(IpAddr);
+
+    #[derive(StrongType)]
+    #[strong_type(opaque)]
+    struct Timeout(Duration);
+
+    #[derive(StrongType)]
+    #[strong_type(opaque)]
+    struct ConfigPath(PathBuf);
+
+    #[test]
+    fn test_opaque() {
+        let localhost = Address::new(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert_eq!(localhost.value(), &IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert_eq!(localhost.primitive(), &IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert_eq!(localhost.to_string(), "Address(127.0.0.1)");
+        assert_eq!(format!("{:?}", localhost), "Address { value: 127.0.0.1 }");
+        assert_eq!(IpAddr::from(localhost), IpAddr::V4(Ipv4Addr::LOCALHOST));
+
+        let addresses =
+            HashSet::from([localhost, Address::from(IpAddr::V4(Ipv4Addr::UNSPECIFIED))]);
+        assert!(addresses.contains(&localhost));
+        assert_impl_all!(Address: Copy, Eq, Ord, Hash, Display);
+        assert_not_impl_any!(Address: Default);
+    }
+
+    #[test]
+    fn test_traits_follow_the_wrapped_type() {
+        let timeouts = BTreeSet::from([
+            Timeout::new(Duration::from_secs(2)),
+            Timeout::new(Duration::from_secs(1)),
+        ]);
+        assert_eq!(
+            timeouts.first(),
+            Some(&Timeout::new(Duration::from_secs(1)))
+        );
+        assert_eq!(Timeout::default().value(), &Duration::ZERO);
+        assert_impl_all!(Timeout: Copy, Eq, Ord, Hash, Default);
+        assert_not_impl_any!(Timeout: Display);
+
+        let mut path = ConfigPath::new("/etc");
+        path.as_mut().push("app.toml");
+        assert_eq!(path.into_inner(), PathBuf::from("/etc/app.toml"));
+        assert_impl_all!(ConfigPath: Clone, Eq, Ord, Hash, Default);
+        assert_not_impl_any!(ConfigPath: Copy, Display);
+    }
+
+    #[test]
+    fn test_any_field_type() {
+        #[derive(StrongType)]
+        #[strong_type(opaque)]
+        struct Rgba([u8; 4]);
+
+        #[derive(StrongType)]
+        #[strong_type(opaque)]
+        struct Ratio(f64);
+
+        assert_eq!(Rgba::new([1, 2, 3, 4]).value()[3], 4);
+        assert_impl_all!(Rgba: Copy, Eq, Ord, Hash, Default);
+        assert_not_impl_any!(Rgba: Display);
+
+        assert!(Ratio::new(0.5) < Ratio::new(1.0));
+        assert_not_impl_any!(Ratio: Eq, Ord, Hash);
+    }
+
+    #[test]
+    fn test_nested_opaque() {
+        #[derive(StrongType)]
+        struct Gateway(Address);
+
+        let gateway = Gateway::new(Address::new(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+        assert_eq!(gateway.primitive(), &IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert_eq!(gateway.to_string(), "Gateway(Address(127.0.0.1))");
+    }
+
+    #[test]
+    fn test_wrapped_type_without_strong_type_traits() {
+        #[derive(StrongType)]
+        #[strong_type(opaque)]
+        struct Tags(HashSet<u8>);
+
+        struct Handle(u32);
+
+        #[derive(StrongType)]
+        #[strong_type(opaque)]
+        struct Connection(Handle);
+
+        let tags = Tags::new(HashSet::from([1, 2]));
+        assert_eq!(tags.clone(), Tags::new(HashSet::from([2, 1])));
+        assert_eq!(format!("{:?}", Tags::default()), "Tags { value: {} }");
+        assert_impl_all!(Tags: Clone, PartialEq, Eq, Debug);
+        assert_not_impl_any!(Tags: PartialOrd, Ord, Hash, StrongType);
+
+        let connection = Connection::new(Handle(7));
+        assert_eq!(connection.value().0, 7);
+        assert_not_impl_any!(Connection: Clone, Debug, PartialEq, PartialOrd, StrongType);
+    }
+}
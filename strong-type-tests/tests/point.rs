@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use strong_type::StrongType;
+
+    #[derive(StrongType)]
+    #[strong_type(addable)]
+    struct TimestampDelta(i64);
+
+    #[derive(StrongType)]
+    #[strong_type(point, delta = TimestampDelta)]
+    struct Timestamp(i64);
+
+    #[test]
+    fn test_point_delta_arithmetic() {
+        let start = Timestamp::new(100);
+        let end = Timestamp::new(250);
+        let step = TimestampDelta::new(50);
+
+        assert_eq!(end - start, TimestampDelta::new(150));
+        assert_eq!(start - end, TimestampDelta::new(-150));
+        assert_eq!(start + step, Timestamp::new(150));
+        assert_eq!(end - step, Timestamp::new(200));
+        assert_eq!(step + start, Timestamp::new(150));
+        assert_eq!(step + step, TimestampDelta::new(100));
+
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(&end - &start, TimestampDelta::new(150));
+            assert_eq!(&start + step, Timestamp::new(150));
+            assert_eq!(start + &step, Timestamp::new(150));
+            assert_eq!(&step + &start, Timestamp::new(150));
+        }
+
+        let mut now = start;
+        now += step;
+        now += &step;
+        assert_eq!(now, Timestamp::new(200));
+        now -= step;
+        assert_eq!(now, Timestamp::new(150));
+    }
+
+    #[test]
+    fn test_float_point() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators, scalable)]
+        struct Displacement(f64);
+
+        #[derive(StrongType)]
+        #[strong_type(point, delta = Displacement)]
+        struct Position(f64);
+
+        let origin = Position::new(1.0);
+        let moved = origin + Displacement::new(2.5) * 2.0;
+        assert_eq!(moved - origin, Displacement::new(5.0));
+    }
+
+    #[derive(StrongType)]
+    #[strong_type(addable)]
+    struct SequenceDelta(u64);
+
+    #[derive(StrongType)]
+    #[strong_type(point, delta = SequenceDelta)]
+    struct Sequence(u64);
+
+    #[test]
+    fn test_unsigned_point() {
+        let first = Sequence::new(3u64);
+        let next = first + SequenceDelta::new(2u64);
+        assert_eq!(next - first, SequenceDelta::new(2u64));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_unsigned_point_difference_overflows() {
+        let _ = Sequence::new(3u64) - Sequence::new(5u64);
+    }
+}
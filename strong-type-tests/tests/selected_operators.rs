@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use strong_type::StrongType;
+
+    #[test]
+    fn test_selected_operators() {
+        #[derive(StrongType)]
+        #[strong_type(ops(add, sub, rem, neg, shl))]
+        struct Offset(i32);
+
+        let x = Offset::new(7);
+        let y = Offset::new(3);
+
+        assert_eq!(x + y, Offset::new(10));
+        assert_eq!(x - y, Offset::new(4));
+        assert_eq!(x % y, Offset::new(1));
+        assert_eq!(-x, Offset::new(-7));
+        assert_eq!(x << 1u8, Offset::new(14));
+        assert_eq!(&x << 2usize, Offset::new(28));
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(&x + &y, Offset::new(10));
+        }
+
+        let mut z = x;
+        z += y;
+        z %= Offset::new(4);
+        z <<= 3i32;
+        assert_eq!(z, Offset::new(16));
+
+        let total: Offset = [x, y].iter().sum();
+        assert_eq!(total, Offset::new(10));
+        assert_eq!(Offset::mean([x, y]), Some(Offset::new(5)));
+    }
+
+    #[test]
+    fn test_selected_operators_with_modes() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "minimal", ops(add, mul))]
+        struct Minimal(u32);
+
+        let mut x = Minimal::new(2u32) + Minimal::new(3u32);
+        x *= Minimal::new(2u32);
+        assert_eq!(x, Minimal::new(10u32));
+        assert_eq!(
+            [Minimal::new(2u32), Minimal::new(3u32)]
+                .into_iter()
+                .product::<Minimal>(),
+            Minimal::new(6u32)
+        );
+
+        #[derive(StrongType)]
+        #[strong_type(auto_operators = "delegated", ops(sub, bitxor, shr))]
+        struct Delegated(i64);
+
+        let x = Delegated::new(12);
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(&x - &Delegated::new(2), Delegated::new(10));
+        }
+        assert_eq!(x ^ Delegated::new(4), Delegated::new(8));
+        assert_eq!(x >> 2u32, Delegated::new(3));
+
+        #[derive(StrongType)]
+        #[strong_type(ops(not, bitand))]
+        struct Flag(bool);
+
+        assert_eq!(!Flag::new(true), Flag::new(false));
+        assert_eq!(Flag::new(true) & Flag::new(false), Flag::new(false));
+    }
+
+    #[test]
+    fn test_selected_operators_compose_with_addable_and_scalable() {
+        #[derive(StrongType)]
+        #[strong_type(ops(rem), addable, scalable)]
+        struct Meter(f64);
+
+        let x = Meter::new(5.0);
+        assert_eq!(x + Meter::new(1.0), Meter::new(6.0));
+        assert_eq!(x % Meter::new(2.0), Meter::new(1.0));
+        assert_eq!(x * 2.0, Meter::new(10.0));
+        assert_eq!(-x, Meter::new(-5.0));
+    }
+}
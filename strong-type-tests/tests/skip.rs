@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+    use std::collections::HashSet;
+    use std::fmt::{Debug, Display};
+    use std::hash::{Hash, Hasher};
+    use strong_type::StrongType;
+
+    #[test]
+    fn test_skip_default() {
+        #[derive(StrongType)]
+        #[strong_type(skip(Default))]
+        struct UserId(u64);
+
+        assert_not_impl_any!(UserId: Default);
+        assert_impl_all!(UserId: Debug, Display, Hash, Ord, AsRef<u64>, AsMut<u64>);
+        assert_eq!(UserId::new(7u64).value(), 7);
+    }
+
+    #[test]
+    fn test_skip_hash_and_equality() {
+        #[derive(StrongType)]
+        #[strong_type(skip(PartialEq, Hash))]
+        struct Email(String);
+
+        impl PartialEq for Email {
+            fn eq(&self, other: &Self) -> bool {
+                self.value().eq_ignore_ascii_case(other.value())
+            }
+        }
+
+        impl Hash for Email {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.value().to_ascii_lowercase().hash(state);
+            }
+        }
+
+        assert_eq!(Email::new("A@example.org"), Email::new("a@example.org"));
+        let emails = [Email::new("A@example.org"), Email::new("a@EXAMPLE.org")]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(emails.len(), 1);
+    }
+
+    #[test]
+    fn test_skip_debug_and_as_mut() {
+        #[derive(StrongType)]
+        #[strong_type(skip(Debug, AsMut, AsRef))]
+        struct Password(String);
+
+        impl Debug for Password {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("Password(***)")
+            }
+        }
+
+        assert_not_impl_any!(Password: AsMut<String>, AsRef<String>);
+        assert_eq!(format!("{:?}", Password::new("hunter2")), "Password(***)");
+    }
+
+    #[test]
+    fn test_skip_display_matches_custom_display() {
+        #[derive(StrongType)]
+        #[strong_type(skip(Display))]
+        struct Celsius(f64);
+
+        impl Display for Celsius {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}°C", self.value())
+            }
+        }
+
+        assert_eq!(Celsius::new(21.5).to_string(), "21.5°C");
+    }
+
+    #[test]
+    fn test_borrowed_keeps_companion_impls() {
+        #[derive(StrongType)]
+        #[strong_type(borrowed = TagStr, skip(Debug, AsRef))]
+        struct Tag(String);
+
+        impl Debug for Tag {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "#{}", self.value())
+            }
+        }
+
+        assert_impl_all!(Tag: AsRef<TagStr>);
+        assert_impl_all!(TagStr: Debug);
+        assert_eq!(format!("{:?}", Tag::new("rust")), "#rust");
+    }
+
+    #[test]
+    fn test_skip_on_tagged_enum() {
+        #[derive(StrongType)]
+        #[strong_type(tagged, skip(Debug))]
+        #[repr(u8)]
+        enum Color {
+            Red = 1,
+            Green = 2,
+        }
+
+        impl Debug for Color {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "Color({})", self.value())
+            }
+        }
+
+        assert_eq!(format!("{:?}", Color::Green), "Color(2)");
+        assert_eq!(Color::Red.to_string(), "Red");
+    }
+}
@@ -186,6 +186,10 @@ mod tests {
         assert!(y >= z);
         assert_eq!(x, z);
         assert_ne!(x, y);
+        assert_eq!(x.max(y), y);
+        assert_eq!(x.min(y), x);
+        assert_eq!(x.min(Meter::NAN), x);
+        assert_eq!(Meter::new(5.0).clamp(x, y), y);
 
         #[derive(StrongType)]
         struct Name(String);
@@ -199,6 +203,15 @@ mod tests {
         assert!(y >= x);
     }
 
+    #[test]
+    #[should_panic]
+    fn test_float_clamp_with_inverted_bounds() {
+        #[derive(StrongType)]
+        struct Meter(f64);
+
+        Meter::new(1.0).clamp(Meter::new(3.0), Meter::new(2.0));
+    }
+
     #[test]
     fn test_strong_type() {
         #[derive(StrongType)]
@@ -285,6 +298,29 @@ mod tests {
         assert_eq!(NAMED_I32, NamedI32::new(1));
     }
 
+    #[test]
+    fn test_const_new_static_str() {
+        use std::borrow::Cow;
+
+        #[derive(StrongType)]
+        struct Tag(Cow<'static, str>);
+        test_type::<Tag>();
+
+        const PROD: Tag = Tag::const_new("prod");
+
+        assert_eq!(PROD, Tag::new("prod"));
+        assert_eq!(PROD, Tag::new(String::from("prod")));
+        assert_eq!(PROD.value(), "prod");
+        assert_eq!(PROD.primitive(), "prod");
+        assert!(Tag::const_new("dev") < PROD);
+        assert_eq!(PROD.to_string(), "Tag(prod)");
+
+        let mut map = HashSet::<Tag>::new();
+        map.insert(PROD);
+        map.insert(Tag::new("prod".to_string()));
+        assert_eq!(map.len(), 1);
+    }
+
     #[test]
     fn test_pub_type() {
         #[derive(StrongType)]
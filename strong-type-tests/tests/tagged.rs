@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::str::FromStr;
+    use strong_type::{Error, ErrorKind, StrongType};
+
+    #[derive(StrongType)]
+    #[strong_type(tagged)]
+    #[repr(u16)]
+    enum Status {
+        Ok = 200,
+        NotFound = 404,
+        Teapot = 418,
+    }
+
+    fn test_type<
+        T: std::fmt::Debug + Copy + Send + Sync + Eq + Ord + std::hash::Hash + StrongType,
+    >() {
+    }
+
+    #[test]
+    fn test_tagged_basics() {
+        test_type::<Status>();
+        assert_eq!(size_of::<Status>(), size_of::<u16>());
+
+        assert_eq!(Status::NotFound.value(), 404);
+        assert_eq!(Status::Teapot.primitive(), 418u16);
+        assert_eq!(Status::Ok.name(), "Ok");
+        assert_eq!(
+            Status::VARIANTS,
+            &[Status::Ok, Status::NotFound, Status::Teapot]
+        );
+        assert!(Status::Ok < Status::Teapot);
+        assert_eq!(Status::NotFound.max(Status::Ok), Status::NotFound);
+
+        let mut set = HashSet::new();
+        set.insert(Status::Ok);
+        set.insert(Status::Ok);
+        set.insert(Status::Teapot);
+        assert_eq!(set.len(), 2);
+
+        const STATUS_CODE: u16 = Status::NotFound.value();
+        assert_eq!(STATUS_CODE, 404);
+    }
+
+    #[test]
+    fn test_tagged_conversion() {
+        assert_eq!(u16::from(Status::Teapot), 418);
+        assert_eq!(Status::try_from(404), Ok(Status::NotFound));
+
+        let error = Status::try_from(500).unwrap_err();
+        assert_eq!(error.type_name(), "Status");
+        assert_eq!(error.value(), "500");
+        assert_eq!(error.kind(), ErrorKind::UnknownVariant);
+        assert_eq!(error.to_string(), "invalid Status '500': unknown variant");
+
+        assert_eq!(Status::from_str("Teapot"), Ok(Status::Teapot));
+        assert_eq!("Ok".parse::<Status>(), Ok(Status::Ok));
+        assert_eq!(
+            "teapot".parse::<Status>(),
+            Err(Error::unknown_variant("Status", "teapot"))
+        );
+    }
+
+    #[test]
+    fn test_tagged_display() {
+        assert_eq!(Status::NotFound.to_string(), "NotFound");
+        assert_eq!(format!("{:?}", Status::NotFound), "NotFound");
+
+        #[derive(StrongType)]
+        #[strong_type(tagged(display = "value"))]
+        #[repr(i8)]
+        enum Level {
+            Low = -1,
+            High = 1,
+        }
+
+        assert_eq!(Level::Low.to_string(), "-1");
+        assert_eq!(Level::High.to_string(), "1");
+        assert_eq!(format!("{:?}", Level::Low), "Low");
+
+        #[derive(StrongType)]
+        #[strong_type(tagged, custom_display)]
+        #[repr(u8)]
+        enum Color {
+            Red,
+            Green,
+        }
+
+        impl std::fmt::Display for Color {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "color #{}", self.value())
+            }
+        }
+
+        assert_eq!(Color::Green.to_string(), "color #1");
+        assert_eq!(Color::try_from(0u8), Ok(Color::Red));
+    }
+}
@@ -1,9 +1,28 @@
+mod access;
+mod addable_primitive;
 mod auto_operators;
+mod bitflags;
+mod borrowed;
+mod collectable;
+mod constraints;
 mod conversion;
 mod custom_underlying;
+mod default;
 mod delegated_operators;
 mod diagnostics;
 mod display;
 mod ergonomics;
+mod function_attributes;
+mod hygiene;
+mod lifetime;
+mod mean;
 mod minimal_operators;
+mod nested;
+mod normalize;
+mod opaque;
+mod point;
+mod selected_operators;
+mod skip;
 mod strong_type;
+mod tagged;
+mod type_alias;
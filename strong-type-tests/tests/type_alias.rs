@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use strong_type::StrongType;
+
+    type Raw = i32;
+    type Text = String;
+    type Label = Cow<'static, str>;
+
+    #[test]
+    fn test_primitive_alias() {
+        #[derive(StrongType)]
+        #[strong_type(primitive = i32, auto_operators)]
+        struct Count(Raw);
+
+        assert_eq!(Count::new(1) + Count::new(2), Count::new(3));
+        assert_eq!(Count::MAX, Count::new(i32::MAX));
+        assert_eq!(Count::new(3).primitive(), 3i32);
+        assert_eq!(Count::default(), Count::ZERO);
+    }
+
+    #[test]
+    fn test_string_alias() {
+        #[derive(StrongType)]
+        #[strong_type(primitive = String, conversion)]
+        struct Name(Text);
+
+        let name = Name::from("Alice");
+        assert_eq!(name.value(), "Alice");
+        assert_eq!(name.to_string(), "Name(Alice)");
+
+        #[derive(StrongType)]
+        #[strong_type(primitive = std::borrow::Cow<'static, str>)]
+        struct Tag(Label);
+
+        const TAG: Tag = Tag::const_new("dev");
+        assert_eq!(TAG.value(), "dev");
+    }
+
+    #[test]
+    fn test_qualified_primitives() {
+        #[derive(StrongType)]
+        #[strong_type(auto_operators)]
+        struct Byte(::core::primitive::u8);
+
+        #[derive(StrongType)]
+        struct Word(std::string::String);
+
+        #[derive(StrongType)]
+        struct Title(std::borrow::Cow<'static, str>);
+
+        assert_eq!(Byte::new(1) + Byte::new(2), Byte::new(3));
+        assert_eq!(Word::new("word").value(), "word");
+        assert_eq!(Title::const_new("title").value(), "title");
+    }
+
+    #[test]
+    fn test_user_type_named_like_a_primitive() {
+        mod money {
+            use strong_type::StrongType;
+
+            #[derive(StrongType)]
+            pub struct String(std::string::String);
+        }
+
+        // A `String` outside of `std::string` is not the standard string, but another strong type
+        #[derive(StrongType)]
+        struct Note(money::String);
+
+        let note = Note::new(money::String::new("memo"));
+        assert_eq!(note.value(), &money::String::new("memo"));
+        assert_eq!(note.primitive(), "memo");
+    }
+}
@@ -0,0 +1,44 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(scalable)]
+struct ScalableString(String);
+
+#[derive(StrongType)]
+#[strong_type(addable)]
+struct AddableBool(bool);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+struct OperatorChar(char);
+
+#[derive(StrongType)]
+#[strong_type(addable, auto_operators)]
+struct AddableWithOperators(i32);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+#[strong_type(auto_operators = "minimal")]
+struct Duplicate(i32);
+
+#[derive(StrongType)]
+#[strong_type(custom_display, skip(Display))]
+struct SkippedTwice(i32);
+
+#[derive(StrongType)]
+#[strong_type(normalize = "lowercase", collectable)]
+struct Host(String);
+
+#[derive(StrongType)]
+#[strong_type(constructor = "pub(crate)", default = 8080)]
+struct Port(u16);
+
+#[derive(StrongType)]
+#[strong_type(constructor = "pub(crate)", borrowed = TagStr)]
+struct Tag(String);
+
+#[derive(StrongType)]
+#[strong_type(constructor = "pub(crate)", auto_operators = "delegated")]
+struct Cents(i64);
+
+fn main() {}
@@ -4,4 +4,27 @@ use strong_type::StrongType;
 #[strong_type(minimal_operators)]
 struct Invalid(i32);
 
+#[derive(StrongType)]
+#[strong_type(addible)]
+struct Misspelled(i32);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators = "minmal")]
+struct MisspelledMode(i32);
+
+#[derive(StrongType)]
+#[strong_type(ops(add, mull))]
+struct MisspelledOperator(i32);
+
+#[derive(StrongType)]
+#[strong_type(skip(Dispaly))]
+struct MisspelledTrait(i32);
+
+#[derive(StrongType)]
+#[strong_type(tagged, custom_dispaly)]
+#[repr(u8)]
+enum MisspelledTagged {
+    A = 1,
+}
+
 fn main() {}
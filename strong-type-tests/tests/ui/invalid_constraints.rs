@@ -0,0 +1,19 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(min_len = 8, max_len = 4)]
+struct Password(String);
+
+#[derive(StrongType)]
+#[strong_type(pattern = "[a-z")]
+struct Word(String);
+
+#[derive(StrongType)]
+#[strong_type(max_len = 4)]
+struct Pin(u32);
+
+#[derive(StrongType)]
+#[strong_type(max_len = 3, charset = "a-z", collectable)]
+struct Code(String);
+
+fn main() {}
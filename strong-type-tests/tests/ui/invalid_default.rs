@@ -0,0 +1,15 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(default = "Guest", charset = "a-z")]
+struct Username(String);
+
+#[derive(StrongType)]
+#[strong_type(default = 1, skip(Default))]
+struct Id(u32);
+
+#[derive(StrongType)]
+#[strong_type(default = -1)]
+struct Count(u32);
+
+fn main() {}
@@ -0,0 +1,17 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+struct Generic<T>(T);
+
+#[derive(StrongType)]
+struct Mutable<'a>(&'a mut str);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+struct Operators<'a>(&'a str);
+
+#[derive(StrongType)]
+#[strong_type(custom_display)]
+struct Bytes<'a>(&'a [u8]);
+
+fn main() {}
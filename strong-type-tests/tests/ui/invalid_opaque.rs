@@ -0,0 +1,12 @@
+use std::time::Duration;
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(opaque, auto_operators)]
+struct Timeout(Duration);
+
+#[derive(StrongType)]
+#[strong_type(opaque, primitive = i32)]
+struct Id(i32);
+
+fn main() {}
@@ -0,0 +1,24 @@
+use strong_type::StrongType;
+
+type Raw = i32;
+
+#[derive(StrongType)]
+struct Base(i32);
+
+#[derive(StrongType)]
+#[strong_type(primitive = i64)]
+struct NotAnAlias(i64);
+
+#[derive(StrongType)]
+#[strong_type(primitive = Vec<u8>)]
+struct Unsupported(Raw);
+
+#[derive(StrongType)]
+#[strong_type(underlying = i32, primitive = i32)]
+struct Both(Base);
+
+#[derive(StrongType)]
+#[strong_type(underlying = crate::money::i32)]
+struct Shadowed(Base);
+
+fn main() {}
@@ -0,0 +1,15 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(skip(Clone))]
+struct Id(u32);
+
+#[derive(StrongType)]
+#[strong_type(borrowed = NameStr, skip(Hash))]
+struct Name(String);
+
+#[derive(StrongType)]
+#[strong_type(skip(Ord))]
+struct Ratio(f64);
+
+fn main() {}
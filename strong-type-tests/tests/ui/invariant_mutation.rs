@@ -0,0 +1,21 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(min_len = 3, max_len = 5, charset = "a-z", conversion)]
+struct Slug(String);
+
+#[derive(StrongType)]
+#[strong_type(normalize = "trim", conversion)]
+struct Name(String);
+
+fn main() {
+    let mut slug = Slug::new("slug");
+    slug.as_mut().push_str("NOT VALID 123");
+    let _ = AsMut::<String>::as_mut(&mut slug);
+    let _: &mut String = (&mut slug).into();
+
+    let mut name = Name::new("name");
+    name.as_mut().push_str("  ");
+    let _ = AsMut::<String>::as_mut(&mut name);
+    let _: &mut String = (&mut name).into();
+}
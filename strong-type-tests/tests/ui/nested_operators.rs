@@ -0,0 +1,20 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+struct Tag(String);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+struct Label(Tag);
+
+#[derive(StrongType)]
+#[strong_type(auto_operators)]
+struct Dollar(i32);
+
+#[derive(StrongType)]
+#[strong_type(bitflags(READ = 1))]
+struct Cash(Dollar);
+
+fn main() {
+    let _ = Label::new(Tag::new("a")) + Label::new(Tag::new("b"));
+}
@@ -0,0 +1,12 @@
+use std::time::Duration;
+use strong_type::StrongType;
+
+type Raw = i32;
+
+#[derive(StrongType)]
+struct Timeout(Duration);
+
+#[derive(StrongType)]
+struct Id(Raw);
+
+fn main() {}
@@ -0,0 +1,13 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(addable)]
+struct TimestampDelta(i64);
+
+#[derive(StrongType)]
+#[strong_type(point, delta = TimestampDelta)]
+struct Timestamp(i64);
+
+fn main() {
+    let _ = Timestamp::new(1) + Timestamp::new(2);
+}
@@ -0,0 +1,40 @@
+mod ids {
+    use strong_type::StrongType;
+
+    #[derive(StrongType)]
+    #[strong_type(constructor = "pub(crate)", accessor = "", immutable)]
+    pub struct UserId(u64);
+
+    #[derive(StrongType)]
+    #[strong_type(constructor = "", min_len = 1)]
+    pub struct Name(String);
+
+    #[derive(StrongType)]
+    #[strong_type(constructor = "", normalize = "trim")]
+    pub struct Key(String);
+
+    #[derive(StrongType)]
+    #[strong_type(constructor = "pub(crate)", addable, addable_primitive, scalable)]
+    pub struct Money(i64);
+
+    #[derive(StrongType)]
+    #[strong_type(bitflags(READ = 1, WRITE = 2), immutable)]
+    pub struct Perm(u8);
+}
+
+use ids::{Key, Money, Name, Perm, UserId};
+use strong_type::delegation::StrongTypeOps;
+
+fn main() {
+    let mut id = UserId::new(7u64);
+    let _ = id.as_mut();
+    let _ = id.into_inner();
+
+    let _ = UserId::default();
+    let _ = "ada".parse::<Name>();
+    let _ = "key".parse::<Key>();
+    let _ = <Money as StrongTypeOps>::from_primitive(42);
+
+    let mut perm = Perm::READ;
+    perm.insert(Perm::WRITE);
+}
@@ -0,0 +1,9 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(auto_operators, shift_rhs = u32)]
+struct CacheSize(u64);
+
+fn main() {
+    let _ = CacheSize::new(1) << 2u8;
+}
@@ -0,0 +1,11 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(tagged)]
+#[repr(u8)]
+enum Status {
+    Ok,
+    Error(u8),
+}
+
+fn main() {}
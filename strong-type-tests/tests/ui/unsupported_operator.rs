@@ -0,0 +1,7 @@
+use strong_type::StrongType;
+
+#[derive(StrongType)]
+#[strong_type(ops(add, neg))]
+struct Count(u32);
+
+fn main() {}
@@ -6,22 +6,183 @@
 //!
 //! See the [crate documentation](https://crates.io/crates/strong-type) for more details and examples.
 //!
+//! The crate is `no_std`. The default `std` feature enables the `alloc` feature, which is
+//! required for strong types wrapping `String` or `Cow<'static, str>`.
 
-use std::fmt::Debug;
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt::{self, Debug, Display};
 
 /// Derive macro to create strong types in Rust.
 pub use strong_type_derive::StrongType;
 
 /// Trait for strong types to obtain the associated underlying type and primitive type.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a strong type, so it cannot be wrapped without an attribute",
+    label = "not a strong type",
+    note = "use #[strong_type(opaque)] to wrap an arbitrary type, or #[strong_type(primitive = <type>)] if the field is an alias of a primitive"
+)]
 pub trait StrongType: Debug + PartialEq + PartialOrd + Clone {
     type UnderlyingType;
     type PrimitiveType;
 }
 
+/// Trait for strong types to expose their value and primitive without naming their types.
+///
+/// It is implemented by the derive for every strong type, so that a strong type wrapping another
+/// one, such as `struct Cash(Dollar)`, can generate `value()` and `primitive()` without an
+/// `underlying` attribute.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a strong type, so it cannot be wrapped without an attribute",
+    label = "not a strong type",
+    note = "use #[strong_type(opaque)] to wrap an arbitrary type, or #[strong_type(primitive = <type>)] if the field is an alias of a primitive"
+)]
+pub trait StrongTypeAccess: StrongType {
+    /// The value as returned by `value()` of a strong type wrapping this one: `Self` for
+    /// numeric, `bool`, `char` and tagged types, and `&Self` otherwise.
+    type Value<'a>
+    where
+        Self: 'a;
+    /// The primitive as returned by `primitive()`: the primitive itself for numeric, `bool`,
+    /// `char` and tagged types, and `&str` for string types.
+    type Primitive<'a>
+    where
+        Self: 'a;
+
+    fn as_value(&self) -> Self::Value<'_>;
+    fn as_primitive(&self) -> Self::Primitive<'_>;
+}
+
+/// Error returned by the fallible APIs of generated strong types, such as `try_new`, `FromStr`
+/// and `TryFrom`.
+///
+/// It records the name of the strong type, the rejected value formatted with `Display`, and the
+/// reason it was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    type_name: &'static str,
+    kind: ErrorKind,
+    #[cfg(feature = "alloc")]
+    value: String,
+    #[cfg(feature = "alloc")]
+    reason: String,
+}
+
+/// The category of an [`Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The value does not name a variant of a tagged enum.
+    UnknownVariant,
+    /// The value violates a constraint such as `min_len`, `max_len`, `charset` or `pattern`.
+    Constraint,
+}
+
+impl Error {
+    /// Creates an error for a value that does not name a variant of a tagged enum.
+    ///
+    /// Without the `alloc` feature, the value is not recorded.
+    pub fn unknown_variant(type_name: &'static str, value: impl Display) -> Self {
+        #[cfg(not(feature = "alloc"))]
+        let _ = value;
+        Self {
+            type_name,
+            kind: ErrorKind::UnknownVariant,
+            #[cfg(feature = "alloc")]
+            value: value.to_string(),
+            #[cfg(feature = "alloc")]
+            reason: ErrorKind::UnknownVariant.description().to_string(),
+        }
+    }
+
+    /// Creates an error for a value that violates a constraint of the strong type.
+    #[cfg(feature = "alloc")]
+    pub fn constraint(type_name: &'static str, value: impl Display, reason: impl Display) -> Self {
+        Self {
+            type_name,
+            kind: ErrorKind::Constraint,
+            value: value.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// The name of the strong type
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The rejected value, formatted with `Display`
+    #[cfg(feature = "alloc")]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Why the value was rejected
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// A human-readable description of why the value was rejected
+    #[cfg(feature = "alloc")]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl ErrorKind {
+    fn description(self) -> &'static str {
+        match self {
+            ErrorKind::UnknownVariant => "unknown variant",
+            ErrorKind::Constraint => "constraint violated",
+        }
+    }
+}
+
+impl Display for Error {
+    #[cfg(feature = "alloc")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid {} '{}': {}",
+            self.type_name, self.value, self.reason
+        )
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {}", self.type_name, self.kind.description())
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Re-exports used by generated code. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::{
+        borrow::{Cow, ToOwned},
+        format,
+        string::String,
+    };
+    #[cfg(feature = "regex")]
+    pub use regex::Regex;
+    #[cfg(feature = "regex")]
+    pub use std::sync::LazyLock;
+}
+
 /// Internal module for operator delegation to reduce binary size.
 ///
 /// This module provides generic helper functions that are shared across all strong types
-/// wrapping the same primitive type, reducing monomorphization cost.
+/// wrapping the same primitive type, reducing monomorphization cost. Helpers that may panic are
+/// `#[track_caller]`, so overflow and division by zero are reported at the operator call site.
 #[doc(hidden)]
 pub mod delegation {
     /// Trait for accessing the underlying primitive value of a strong type.
@@ -42,50 +203,55 @@ pub mod delegation {
 
     /// Shared add implementation - monomorphized once per primitive type
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_add<T>(lhs: T, rhs: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Add<Output = T::Primitive>,
+        T::Primitive: core::ops::Add<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() + rhs.to_primitive())
     }
 
     /// Shared sub implementation - monomorphized once per primitive type
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_sub<T>(lhs: T, rhs: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Sub<Output = T::Primitive>,
+        T::Primitive: core::ops::Sub<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() - rhs.to_primitive())
     }
 
     /// Shared mul implementation - monomorphized once per primitive type
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_mul<T>(lhs: T, rhs: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Mul<Output = T::Primitive>,
+        T::Primitive: core::ops::Mul<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() * rhs.to_primitive())
     }
 
     /// Shared div implementation - monomorphized once per primitive type
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_div<T>(lhs: T, rhs: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Div<Output = T::Primitive>,
+        T::Primitive: core::ops::Div<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() / rhs.to_primitive())
     }
 
     /// Shared rem implementation - monomorphized once per primitive type
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_rem<T>(lhs: T, rhs: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Rem<Output = T::Primitive>,
+        T::Primitive: core::ops::Rem<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() % rhs.to_primitive())
     }
@@ -95,7 +261,7 @@ pub mod delegation {
     pub fn delegate_bitand<T>(lhs: T, rhs: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::BitAnd<Output = T::Primitive>,
+        T::Primitive: core::ops::BitAnd<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() & rhs.to_primitive())
     }
@@ -105,7 +271,7 @@ pub mod delegation {
     pub fn delegate_bitor<T>(lhs: T, rhs: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::BitOr<Output = T::Primitive>,
+        T::Primitive: core::ops::BitOr<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() | rhs.to_primitive())
     }
@@ -115,7 +281,7 @@ pub mod delegation {
     pub fn delegate_bitxor<T>(lhs: T, rhs: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::BitXor<Output = T::Primitive>,
+        T::Primitive: core::ops::BitXor<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() ^ rhs.to_primitive())
     }
@@ -126,10 +292,11 @@ pub mod delegation {
 
     /// Shared neg implementation - monomorphized once per primitive type
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_neg<T>(val: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Neg<Output = T::Primitive>,
+        T::Primitive: core::ops::Neg<Output = T::Primitive>,
     {
         T::from_primitive(-val.to_primitive())
     }
@@ -139,7 +306,7 @@ pub mod delegation {
     pub fn delegate_not<T>(val: T) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Not<Output = T::Primitive>,
+        T::Primitive: core::ops::Not<Output = T::Primitive>,
     {
         T::from_primitive(!val.to_primitive())
     }
@@ -150,10 +317,11 @@ pub mod delegation {
 
     /// Shared shl implementation - monomorphized once per primitive type
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_shl<T, Rhs>(lhs: T, rhs: Rhs) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Shl<Rhs, Output = T::Primitive>,
+        T::Primitive: core::ops::Shl<Rhs, Output = T::Primitive>,
         Rhs: Copy,
     {
         T::from_primitive(lhs.to_primitive() << rhs)
@@ -161,10 +329,11 @@ pub mod delegation {
 
     /// Shared shr implementation - monomorphized once per primitive type
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_shr<T, Rhs>(lhs: T, rhs: Rhs) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Shr<Rhs, Output = T::Primitive>,
+        T::Primitive: core::ops::Shr<Rhs, Output = T::Primitive>,
         Rhs: Copy,
     {
         T::from_primitive(lhs.to_primitive() >> rhs)
@@ -174,33 +343,88 @@ pub mod delegation {
     // Scalar Operations
     // ============================================================================
 
+    /// Shared scalar add implementation
+    #[inline(never)]
+    #[track_caller]
+    pub fn delegate_scalar_add<T>(lhs: T, rhs: T::Primitive) -> T
+    where
+        T: StrongTypeOps,
+        T::Primitive: core::ops::Add<Output = T::Primitive>,
+    {
+        T::from_primitive(lhs.to_primitive() + rhs)
+    }
+
+    /// Shared scalar sub implementation
+    #[inline(never)]
+    #[track_caller]
+    pub fn delegate_scalar_sub<T>(lhs: T, rhs: T::Primitive) -> T
+    where
+        T: StrongTypeOps,
+        T::Primitive: core::ops::Sub<Output = T::Primitive>,
+    {
+        T::from_primitive(lhs.to_primitive() - rhs)
+    }
+
     /// Shared scalar mul implementation
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_scalar_mul<T>(lhs: T, rhs: T::Primitive) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Mul<Output = T::Primitive>,
+        T::Primitive: core::ops::Mul<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() * rhs)
     }
 
     /// Shared scalar div implementation
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_scalar_div<T>(lhs: T, rhs: T::Primitive) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Div<Output = T::Primitive>,
+        T::Primitive: core::ops::Div<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() / rhs)
     }
 
     /// Shared scalar rem implementation
     #[inline(never)]
+    #[track_caller]
     pub fn delegate_scalar_rem<T>(lhs: T, rhs: T::Primitive) -> T
     where
         T: StrongTypeOps,
-        T::Primitive: std::ops::Rem<Output = T::Primitive>,
+        T::Primitive: core::ops::Rem<Output = T::Primitive>,
     {
         T::from_primitive(lhs.to_primitive() % rhs)
     }
+
+    // ============================================================================
+    // Iterator Folds
+    // ============================================================================
+
+    /// Shared sum implementation, folding the primitives with the primitive `Sum`
+    ///
+    /// The iterator is taken as a trait object, so this is instantiated once per strong type
+    /// rather than once per iterator adapter.
+    #[inline(never)]
+    pub fn delegate_sum<T>(iter: &mut dyn Iterator<Item = T>) -> T
+    where
+        T: StrongTypeOps,
+        T::Primitive: core::iter::Sum,
+    {
+        T::from_primitive(iter.map(T::to_primitive).sum())
+    }
+
+    /// Shared product implementation, folding the primitives with the primitive `Product`
+    ///
+    /// The iterator is taken as a trait object, so this is instantiated once per strong type
+    /// rather than once per iterator adapter.
+    #[inline(never)]
+    pub fn delegate_product<T>(iter: &mut dyn Iterator<Item = T>) -> T
+    where
+        T: StrongTypeOps,
+        T::Primitive: core::iter::Product,
+    {
+        T::from_primitive(iter.map(T::to_primitive).product())
+    }
 }